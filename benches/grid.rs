@@ -0,0 +1,182 @@
+//! Compares the flat [`Grid`] storage against the nested `Vec<Vec<T>>` layout it replaced
+//! by running every day 16 part two flood-fill over the real input.
+//!
+//! Run with `cargo bench --bench grid`.
+use std::{
+    collections::VecDeque,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::template::{read_file, runner::print_read_error};
+use advent_of_code::{day, parse_to_vec_vec_grid, vec_vec_grid_with_type, year, Grid, Point};
+
+const ROUNDS: usize = 25;
+
+/// The storage layout `Grid<T>` used before it moved to a single buffer.
+struct NestedGrid<T> {
+    grid: Vec<Vec<T>>,
+    width: usize,
+    height: usize,
+}
+
+trait Cells<T> {
+    fn cell(&self, position: &Point) -> Option<&T>;
+    fn cell_mut(&mut self, position: &Point) -> Option<&mut T>;
+}
+
+impl<T> Cells<T> for Grid<T> {
+    fn cell(&self, position: &Point) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn cell_mut(&mut self, position: &Point) -> Option<&mut T> {
+        self.get_mut(position)
+    }
+}
+
+impl<T> NestedGrid<T> {
+    fn in_bounds(&self, position: &Point) -> bool {
+        position.x >= 0
+            && position.x < self.width as i32
+            && position.y >= 0
+            && position.y < self.height as i32
+    }
+}
+
+impl<T> Cells<T> for NestedGrid<T> {
+    fn cell(&self, position: &Point) -> Option<&T> {
+        if self.in_bounds(position) {
+            Some(&self.grid[position.y as usize][position.x as usize])
+        } else {
+            None
+        }
+    }
+
+    fn cell_mut(&mut self, position: &Point) -> Option<&mut T> {
+        if self.in_bounds(position) {
+            Some(&mut self.grid[position.y as usize][position.x as usize])
+        } else {
+            None
+        }
+    }
+}
+
+const DIRECTIONS: [Point; 4] = [
+    Point { x: 0, y: -1 },
+    Point { x: 1, y: 0 },
+    Point { x: 0, y: 1 },
+    Point { x: -1, y: 0 },
+];
+
+/// Day 16's beam flood-fill, with directions encoded as indices into [`DIRECTIONS`]
+/// and the visited directions of a tile stored as a bit mask.
+fn energize(grid: &impl Cells<char>, visited: &mut impl Cells<u8>, start: Point, dir: usize) {
+    let mut beams = VecDeque::from([(start, dir)]);
+
+    while let Some((mut position, mut dir)) = beams.pop_front() {
+        loop {
+            position += DIRECTIONS[dir];
+
+            let (Some(tile), Some(seen)) = (grid.cell(&position), visited.cell_mut(&position))
+            else {
+                break;
+            };
+
+            if *seen & (1 << dir) != 0 {
+                break;
+            }
+            *seen |= 1 << dir;
+
+            match (tile, dir) {
+                ('|', 1 | 3) => {
+                    beams.extend([(position, 0), (position, 2)]);
+                    break;
+                }
+                ('-', 0 | 2) => {
+                    beams.extend([(position, 1), (position, 3)]);
+                    break;
+                }
+                ('/', _) => dir = [1, 0, 3, 2][dir],
+                ('\\', _) => dir = [3, 2, 1, 0][dir],
+                _ => {}
+            }
+        }
+    }
+}
+
+fn starts(width: i32, height: i32) -> Vec<(Point, usize)> {
+    let vertical = (0..width).flat_map(|x| [(Point::new(x, height), 0), (Point::new(x, -1), 2)]);
+    let horizontal = (0..height).flat_map(|y| [(Point::new(-1, y), 1), (Point::new(width, y), 3)]);
+    vertical.chain(horizontal).collect()
+}
+
+fn run_flat(grid: &Grid<char>) -> usize {
+    starts(grid.width as i32, grid.height as i32)
+        .into_iter()
+        .map(|(start, dir)| {
+            let mut visited: Grid<u8> = vec_vec_grid_with_type(grid.width, grid.height);
+            energize(grid, &mut visited, start, dir);
+            visited.cells.iter().filter(|v| **v != 0).count()
+        })
+        .max()
+        .unwrap()
+}
+
+fn run_nested(grid: &NestedGrid<char>) -> usize {
+    starts(grid.width as i32, grid.height as i32)
+        .into_iter()
+        .map(|(start, dir)| {
+            let mut visited = NestedGrid {
+                grid: vec![vec![0_u8; grid.width]; grid.height],
+                width: grid.width,
+                height: grid.height,
+            };
+            energize(grid, &mut visited, start, dir);
+            visited.grid.iter().flatten().filter(|v| **v != 0).count()
+        })
+        .max()
+        .unwrap()
+}
+
+fn median(f: impl Fn() -> usize) -> Duration {
+    let mut samples: Vec<Duration> = (0..ROUNDS)
+        .map(|_| {
+            let timer = Instant::now();
+            black_box(f());
+            timer.elapsed()
+        })
+        .collect();
+    samples.sort();
+    samples[ROUNDS / 2]
+}
+
+fn main() {
+    let input = match read_file("inputs", year!(2023), day!(16)) {
+        Ok(input) => input,
+        Err(e) => {
+            print_read_error(&e);
+            std::process::exit(1);
+        }
+    };
+
+    let flat = parse_to_vec_vec_grid(&input, |c| c);
+    let nested = NestedGrid {
+        grid: flat.row_slices().map(<[char]>::to_vec).collect(),
+        width: flat.width,
+        height: flat.height,
+    };
+
+    assert_eq!(run_flat(&flat), run_nested(&nested));
+
+    let flat_time = median(|| run_flat(&flat));
+    let nested_time = median(|| run_nested(&nested));
+
+    println!("Day 16 part two flood-fills, median of {ROUNDS} rounds:");
+    println!("  Vec<Vec<T>>: {nested_time:.1?}");
+    println!("  flat Vec<T>: {flat_time:.1?}");
+    println!(
+        "  speedup:     {:.2}x",
+        nested_time.as_secs_f64() / flat_time.as_secs_f64()
+    );
+}
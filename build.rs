@@ -0,0 +1,54 @@
+//! Generates the registry of solutions the main binary uses to run every day in-process.
+//!
+//! Every `src/bin/YYYY_DD.rs` is compiled into the main binary as a module as well, collecting the
+//! `SOLUTION` constants created by `advent_of_code::solution!` into `SOLUTIONS`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut days: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter_map(|path| {
+                    let stem = path.file_stem()?.to_str()?;
+                    let (year, day) = stem.split_once('_')?;
+                    let is_day = path.extension()? == "rs"
+                        && year.len() == 4
+                        && day.len() == 2
+                        && (year.chars().chain(day.chars())).all(|c| c.is_ascii_digit());
+                    is_day.then(|| (stem.to_string(), path.display().to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    let mut registry = String::new();
+
+    // day modules are left out of test builds, their tests already run as part of each binary.
+    for (day, path) in &days {
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {path:?}]\nmod day{day};\n"
+        ));
+    }
+
+    registry.push_str(
+        "\n#[cfg(not(test))]\npub const SOLUTIONS: &[advent_of_code::template::Solution] = &[\n",
+    );
+    for (day, _) in &days {
+        registry.push_str(&format!("    day{day}::SOLUTION,\n"));
+    }
+    registry.push_str(
+        "];\n\n#[cfg(test)]\npub const SOLUTIONS: &[advent_of_code::template::Solution] = &[];\n",
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
@@ -1,16 +1,16 @@
 use std::ops::Add;
 
+use advent_of_code::parse::{comma_list, key_value, parse, ParseError};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, space0},
+    character::complete::{space1, u32},
     multi::separated_list1,
-    sequence::tuple,
+    sequence::{preceded, separated_pair},
     IResult, Parser,
 };
-use thiserror::Error;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 #[derive(Debug)]
 struct CubeCollection {
@@ -43,22 +43,12 @@ struct Game {
 }
 
 fn parse_game(line: &str) -> IResult<&str, u32> {
-    tuple((
-        tag("Game "),
-        digit1,
-        tag(": "),
-    ))
-    .parse(line).map(|(rest, (_, id, _))| { Ok((rest, id.parse().unwrap())) })?
+    preceded(tag("Game "), u32)(line)
 }
 
 fn parse_single(line: &str) -> IResult<&str, CubeCollection> {
-    tuple((
-        digit1,
-        space0,
-        alt((tag("red"), tag("green"), tag("blue"))),
-    ))
-    .parse(line).map(|(rest,(count, _, color) )| {
-        let count = count.parse().unwrap();
+    separated_pair(u32, space1, alt((tag("red"), tag("green"), tag("blue"))))
+    .parse(line).map(|(rest,(count, color) )| {
         match color {
             "red" => Ok((
                 rest,
@@ -90,7 +80,7 @@ fn parse_single(line: &str) -> IResult<&str, CubeCollection> {
 }
 
 fn parse_round(line: &str) -> IResult<&str, CubeCollection> {
-    let (rest, cc) = separated_list1(tag(", "), parse_single)(line)?;
+    let (rest, cc) = comma_list(parse_single)(line)?;
     let res = cc.into_iter().fold(
         CubeCollection {
             red: 0,
@@ -106,19 +96,11 @@ fn parse_rounds(line: &str) -> IResult<&str, Vec<CubeCollection>> {
     separated_list1(tag("; "), parse_round)(line)
 }
 
-#[derive(Error, Debug)]
-pub enum ParseGameError {
-    #[error("Could not parse Game")]
-    Parse,
-}
-
 impl TryFrom<&str> for Game {
-    type Error = ParseGameError;
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let (rest, id) = parse_game(value).or(Err(ParseGameError::Parse))?;
-        let (_, rounds) = parse_rounds(rest).or(Err(ParseGameError::Parse))?;
-
+        let (id, rounds) = parse(value, key_value(parse_game, parse_rounds))?;
         Ok(Game { id, rounds })
     }
 }
@@ -204,13 +186,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(2286));
     }
 }
@@ -1,6 +1,6 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
-use std::collections::HashMap;
+use advent_of_code::{parse_to_vec_vec_grid, Grid, Point};
 
 #[derive(Default, Debug)]
 struct PartNumber {
@@ -10,14 +10,13 @@ struct PartNumber {
     value: i32,
 }
 
-fn check_vicinity(part: &PartNumber, map: &HashMap<(i32, i32), char>) -> Option<i32> {
-    for y in (part.y - 1)..=(part.y + 1) {
-        for x in (part.x1 - 1)..=(part.x2 + 1) {
-            if let Some(c) = map.get(&(x, y)) {
-                if !c.is_ascii_digit() && *c != '.' {
-                    return Some(part.value);
-                }
-            }
+fn check_vicinity(part: &PartNumber, map: &Grid<char>) -> Option<i32> {
+    for x in part.x1..=part.x2 {
+        let symbol = map
+            .neighbors8(&Point::new(x, part.y))
+            .any(|(_, c)| !c.is_ascii_digit() && *c != '.');
+        if symbol {
+            return Some(part.value);
         }
     }
     None
@@ -39,17 +38,13 @@ pub fn part_two(input: &str) -> Option<u32> {
     let (map, parts) = extract_parts_and_map(input);
 
     let mut sum: i32 = 0;
-    for ((x,y), value) in map.iter() {
-        if *value != '*' {
-            continue;
-        }
-
+    for Point { x, y } in map.find_all(&'*') {
         let mut adj = vec![];
         for part in &parts {
             if (part.y - y).abs() > 1 {
                 continue;
             }
-            if *x >= part.x1 - 1 && *x <= part.x2 + 1 {
+            if x >= part.x1 - 1 && x <= part.x2 + 1 {
                 adj.push(part.value);
             } 
         }
@@ -60,8 +55,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(sum as u32)
 }
 
-fn extract_parts_and_map(input: &str) -> (HashMap<(i32, i32), char>, Vec<PartNumber>) {
-    let mut token: HashMap<(i32, i32), char> = HashMap::new();
+fn extract_parts_and_map(input: &str) -> (Grid<char>, Vec<PartNumber>) {
     let mut parts: Vec<PartNumber> = Vec::new();
 
     let mut maxx = 0;
@@ -90,7 +84,6 @@ fn extract_parts_and_map(input: &str) -> (HashMap<(i32, i32), char>, Vec<PartNum
                 part = PartNumber::default();
                 digits.clear();
             }
-            token.insert((x as i32, y as i32), c);
             maxx = maxx.max(x);
         }
         if ongoing {
@@ -100,7 +93,7 @@ fn extract_parts_and_map(input: &str) -> (HashMap<(i32, i32), char>, Vec<PartNum
             digits.clear();
         }
     }
-    (token, parts)
+    (parse_to_vec_vec_grid(input, |c| c), parts)
 }
 
 #[cfg(test)]
@@ -109,13 +102,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(467835));
     }
 }
@@ -1,25 +1,32 @@
 use std::collections::HashSet;
 
+use advent_of_code::parse::{int_list, key_value, lines, parse};
 use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{space1, u32},
+    sequence::{delimited, preceded, separated_pair},
+    IResult,
+};
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
-fn get_numbers(numbers: &str) -> HashSet<u32> {
-    numbers
-        .split_whitespace()
-        .map(|number| number.parse().unwrap())
-        .collect()
+/// Parses `Card 1: 41 48 83 | 83 86  6` into the card's numbers and the winning numbers.
+fn parse_card(line: &str) -> IResult<&str, (Vec<i64>, Vec<i64>)> {
+    let (rest, (_, numbers)) = key_value(
+        preceded(tag("Card"), preceded(space1, u32)),
+        separated_pair(int_list, delimited(space1, tag("|"), space1), int_list),
+    )(line)?;
+    Ok((rest, numbers))
 }
 
-fn calculate_winning(input: &str) -> impl Iterator<Item = u32> + '_ {
-    let wins = input.trim().lines().map(|line| {
-        let (_, rest) = line.split_once(':').unwrap();
-        let (my, winning) = rest.split_once('|').unwrap();
-        let my = get_numbers(my);
-        let winning = get_numbers(winning);
+fn calculate_winning(input: &str) -> impl Iterator<Item = u32> {
+    let cards = parse(input, lines(parse_card)).unwrap();
+    cards.into_iter().map(|(my, winning)| {
+        let my: HashSet<i64> = my.into_iter().collect();
+        let winning: HashSet<i64> = winning.into_iter().collect();
         my.intersection(&winning).count() as u32
-    });
-    wins
+    })
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -47,13 +54,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(30));
     }
 }
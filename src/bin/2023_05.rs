@@ -0,0 +1,200 @@
+use advent_of_code::{
+    parse::{self, blank_line, blocks, int_list, key_value, section, ParseError},
+    Interval, IntervalMap, RangeSet,
+};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i64, space1},
+    sequence::{separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+advent_of_code::solution!(2023, 5, parse);
+
+#[derive(Debug)]
+struct SeedList {
+    seeds: Vec<i64>,
+}
+
+#[derive(Debug)]
+struct TranslationMap {
+    name: String,
+    mapping: IntervalMap,
+}
+
+impl PartialEq for TranslationMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.mapping == other.mapping
+    }
+}
+
+impl PartialEq for SeedList {
+    fn eq(&self, other: &Self) -> bool {
+        self.seeds == other.seeds
+    }
+}
+
+/// The seeds and the chain of maps they are translated through.
+pub struct Almanac {
+    seed_list: SeedList,
+    maps: Vec<TranslationMap>,
+}
+
+pub fn parse(input: &str) -> Almanac {
+    let (seed_list, maps) = parse_all(input).unwrap();
+    Almanac { seed_list, maps }
+}
+
+fn parse_all(input: &str) -> Result<(SeedList, Vec<TranslationMap>), ParseError> {
+    parse::parse(
+        input,
+        separated_pair(parse_seed_list, blank_line, blocks(parse_map)),
+    )
+}
+
+fn parse_seed_list(line: &str) -> IResult<&str, SeedList> {
+    key_value(tag("seeds"), int_list)
+        .parse(line)
+        .map(|(rest, (_, seeds))| (rest, SeedList { seeds }))
+}
+
+/// Parses a `dest src length` line into the source interval and the offset it is shifted by.
+fn parse_map_range(line: &str) -> IResult<&str, (Interval, i64)> {
+    tuple((terminated(i64, space1), terminated(i64, space1), i64))(line)
+        .map(|(rest, (dest, src, length))| (rest, (Interval::from_len(src, length), dest - src)))
+}
+
+fn parse_map(line: &str) -> IResult<&str, TranslationMap> {
+    section(parse_map_range)
+        .parse(line)
+        .map(|(rest, (name, ranges))| {
+            (
+                rest,
+                TranslationMap {
+                    name: name.to_string(),
+                    mapping: ranges.into_iter().collect(),
+                },
+            )
+        })
+}
+
+pub fn part_one(almanac: &Almanac) -> Option<u32> {
+    let result = almanac.seed_list.seeds.iter().fold(i64::MAX, |acc, seed| {
+        let location = almanac
+            .maps
+            .iter()
+            .fold(*seed, |value, map| map.mapping.map_value(value));
+        acc.min(location)
+    });
+    Some(result as u32)
+}
+
+pub fn part_two(almanac: &Almanac) -> Option<u32> {
+    let seeds: RangeSet = almanac
+        .seed_list
+        .seeds
+        .iter()
+        .tuples()
+        .map(|(start, len)| Interval::from_len(*start, *len))
+        .collect();
+
+    let locations = almanac
+        .maps
+        .iter()
+        .fold(seeds, |set, map| map.mapping.map_set(&set));
+    locations.min().map(|location| location as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::parse::label;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_full() {
+        let data = advent_of_code::template::read_file("examples", YEAR, DAY).unwrap();
+        assert!(parse_all(&data).is_ok());
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let result = parse_map(
+            "soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15",
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                TranslationMap {
+                    name: "soil-to-fertilizer map".to_string(),
+                    mapping: IntervalMap::from_iter([
+                        (Interval::from_len(15, 37), -15),
+                        (Interval::from_len(52, 2), -15),
+                        (Interval::from_len(0, 15), 39),
+                    ])
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_map_range() {
+        let result = parse_map_range("50 98 2");
+        assert_eq!(result, Ok(("", (Interval::from_len(98, 2), -48))));
+    }
+
+    #[test]
+    fn test_map_range_end_is_exclusive() {
+        let (_, (source, offset)) = parse_map_range("50 98 2").unwrap();
+        let mapping = IntervalMap::from_iter([(source, offset)]);
+        assert_eq!(mapping.map_value(99), 51);
+        assert_eq!(mapping.map_value(100), 100);
+    }
+
+    #[test]
+    fn test_parse_map_name() {
+        let result = label("seed-to-soil map:");
+        assert_eq!(result, Ok(("", "seed-to-soil map")));
+    }
+
+    #[test]
+    fn test_parse_seed_list() {
+        let result = parse_seed_list("seeds: 79 14 55 13");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                SeedList {
+                    seeds: vec![79, 14, 55, 13]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_separated_list() {
+        let result = int_list("79 14 55 13");
+        assert_eq!(result, Ok(("", vec![79, 14, 55, 13])));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(
+            &advent_of_code::template::read_file("examples", YEAR, DAY).unwrap(),
+        ));
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(
+            &advent_of_code::template::read_file("examples", YEAR, DAY).unwrap(),
+        ));
+        assert_eq!(result, Some(46));
+    }
+}
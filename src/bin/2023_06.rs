@@ -0,0 +1,80 @@
+use advent_of_code::{
+    math::count_negative_quadratic,
+    parse::{int_list, key_value, parse, ParseError},
+};
+use itertools::Itertools;
+use nom::{
+    character::complete::{alpha1, line_ending},
+    sequence::separated_pair,
+    IResult,
+};
+
+advent_of_code::solution!(2023, 6);
+
+fn parse_row(line: &str) -> IResult<&str, Vec<i64>> {
+    let (rest, (_, values)) = key_value(alpha1, int_list)(line)?;
+    Ok((rest, values))
+}
+
+/// Parses the `Time:` and `Distance:` rows.
+fn parse_races(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    parse(input, separated_pair(parse_row, line_ending, parse_row))
+}
+
+/// Reads a row as a single number by ignoring the spaces between its digits.
+fn join_digits(values: &[i64]) -> i64 {
+    values.iter().join("").parse().unwrap()
+}
+
+/// Counts the hold times `x` for which `x * (time - x) > distance`.
+fn ways_to_win(time: i64, distance: i64) -> usize {
+    count_negative_quadratic(1, -time, distance) as usize
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (times, distances) = parse_races(input).unwrap();
+
+    let a: usize = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| ways_to_win(time, distance))
+        .product();
+
+    Some(a as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (times, distances) = parse_races(input).unwrap();
+    let time = join_digits(&times);
+    let distance = join_digits(&distances);
+
+    Some(ways_to_win(time, distance) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_races() {
+        let races = parse_races("Time:      7  15   30\nDistance:  9  40  200");
+        assert_eq!(races, Ok((vec![7, 15, 30], vec![9, 40, 200])));
+    }
+
+    #[test]
+    fn test_join_digits() {
+        assert_eq!(join_digits(&[7, 15, 30]), 71530);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(288));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(71503));
+    }
+}
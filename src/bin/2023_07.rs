@@ -2,7 +2,7 @@ use itertools::Itertools;
 use once_cell::sync::Lazy;
 use std::{cmp::Ordering, collections::HashMap, sync::Mutex};
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 static CARD_RANK1: Lazy<Mutex<HashMap<char, u32>>> = Lazy::new(|| {
     let m: HashMap<char, u32> = [
@@ -180,13 +180,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(6440));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(5905));
     }
 }
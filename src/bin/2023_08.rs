@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use advent_of_code::{
+    math::lcm_all,
+    parse::{blank_line, lines, parse},
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, alphanumeric1},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+advent_of_code::solution!(2023, 8);
+
+type Nodes<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+/// Parses `AAA = (BBB, CCC)`.
+fn parse_node(line: &str) -> IResult<&str, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            tag("("),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            tag(")"),
+        ),
+    )(line)
+}
+
+/// Splits the input into the instructions and the network.
+fn parse_network(input: &str) -> (&str, Nodes<'_>) {
+    let (path, nodes) =
+        parse(input, separated_pair(alpha1, blank_line, lines(parse_node))).unwrap();
+    (path, nodes.into_iter().collect())
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let (path, nodes) = parse_network(input);
+    let current = "AAA";
+    Some(solve_path(path, &nodes, current, |c| c == "ZZZ"))
+}
+
+fn solve_path<'a>(
+    path: &str,
+    nodes: &Nodes<'a>,
+    mut current: &'a str,
+    end_condition: impl Fn(&str) -> bool,
+) -> u64 {
+    for (i, path) in path.chars().cycle().enumerate() {
+        let (l, r) = nodes.get(current).unwrap();
+        current = match path {
+            'R' => r,
+            'L' => l,
+            _ => panic!("unknown path option"),
+        };
+        if end_condition(current) {
+            return i as u64 + 1;
+        }
+    }
+    panic!("unsolvable")
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (path, nodes) = parse_network(input);
+
+    let cycle_lengths: Vec<u64> = nodes
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|node| solve_path(path, &nodes, node, |c| c.ends_with('Z')))
+        .collect();
+    lcm_all(&cycle_lengths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 1).unwrap());
+        assert_eq!(result, Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 2).unwrap());
+        assert_eq!(result, Some(6));
+    }
+}
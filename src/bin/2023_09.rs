@@ -0,0 +1,33 @@
+use advent_of_code::{
+    math::{extrapolate_backward, extrapolate_forward},
+    parse::{int_list, lines, parse},
+};
+
+advent_of_code::solution!(2023, 9);
+
+pub fn part_one(input: &str) -> Option<i64> {
+    let histories = parse(input, lines(int_list)).unwrap();
+    Some(histories.iter().map(|h| extrapolate_forward(h)).sum())
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let histories = parse(input, lines(int_list)).unwrap();
+    Some(histories.iter().map(|h| extrapolate_backward(h)).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(114));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(2));
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::{HashMap, VecDeque};
+
+use advent_of_code::search::bfs;
+use advent_of_code::{parse_to_vec_vec_grid, Direction, Grid, Point};
+
+advent_of_code::solution!(2023, 10);
+
+fn connections(pipe: char) -> &'static [Direction] {
+    match pipe {
+        '|' => &[Direction::Up, Direction::Down],
+        '-' => &[Direction::Right, Direction::Left],
+        'L' => &[Direction::Up, Direction::Right],
+        'J' => &[Direction::Up, Direction::Left],
+        '7' => &[Direction::Down, Direction::Left],
+        'F' => &[Direction::Right, Direction::Down],
+        _ => &[],
+    }
+}
+
+/// Replaces the start tile with the pipe that connects it to its neighbours.
+fn resolve_start(start: Point, grid: &mut Grid<char>) {
+    let allowed_directions: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|d| {
+            grid.get(&(start + d.offset()))
+                .is_some_and(|pipe| connections(*pipe).contains(&d.opposite()))
+        })
+        .collect();
+
+    grid[start] = ['|', '-', 'L', 'J', '7', 'F']
+        .into_iter()
+        .find(|pipe| connections(*pipe) == allowed_directions)
+        .expect("start must connect to exactly two pipes");
+}
+
+/// Walks the loop from the start in both directions, returning the distance to every pipe in the loop.
+fn loop_distances(start: Point, grid: &Grid<char>) -> HashMap<Point, usize> {
+    let follow_pipe = |p: &Point| {
+        let pipe = grid[*p];
+        connections(pipe)
+            .iter()
+            .map(|d| *p + d.offset())
+            .collect::<Vec<_>>()
+    };
+    bfs(start, follow_pipe, |_| false).distances
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut grid = parse_to_vec_vec_grid(input, |c| c);
+    let start = grid.find(&'S')?;
+    resolve_start(start, &mut grid);
+
+    let scores = loop_distances(start, &grid);
+
+    Some(*scores.values().max().unwrap() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut grid = parse_to_vec_vec_grid(input, |c| c);
+    let start = grid.find(&'S')?;
+
+    resolve_start(start, &mut grid);
+
+    let scores = loop_distances(start, &grid);
+
+    let mut count = 0;
+    for y in 0..grid.height {
+        let mut row: VecDeque<_> = grid.row(y).collect();
+
+        let mut inside = false;
+        let mut x = 0;
+        while let Some(c) = row.pop_front() {
+            let current = Point::new(x, y as i32);
+            x += 1;
+
+            if scores.contains_key(&current) {
+                if *c == '|' {
+                    inside = !inside;
+                }
+
+                if *c == 'F' {
+                    check_transitions(&mut x, &mut row, &mut inside, '7', 'J');
+                }
+
+                if *c == 'L' {
+                    check_transitions(&mut x, &mut row, &mut inside, 'J', '7');
+                }
+            } else if inside {
+                count += 1;
+            }
+        }
+    }
+
+    Some(count)
+}
+
+fn check_transitions(
+    x: &mut i32,
+    row: &mut VecDeque<&char>,
+    inside: &mut bool,
+    no_t: char,
+    t: char,
+) {
+    loop {
+        *x += 1;
+        match row.pop_front() {
+            Some(x) if *x == no_t => break,
+            Some(x) if *x == t => {
+                *inside = !*inside;
+                break;
+            }
+            Some(x) if *x == '-' => continue,
+            Some(_) => panic!("not possible"),
+            None => panic!("not possible"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_1() {
+        let result =
+            part_one(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 1).unwrap());
+        assert_eq!(result, Some(4));
+    }
+
+    #[test]
+    fn test_part_two_1() {
+        let result =
+            part_two(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 2).unwrap());
+        assert_eq!(result, Some(4));
+    }
+    #[test]
+    fn test_part_two_2() {
+        let result =
+            part_two(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 3).unwrap());
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part_two_3() {
+        let result =
+            part_two(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 4).unwrap());
+        assert_eq!(result, Some(10));
+    }
+}
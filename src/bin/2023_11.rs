@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use advent_of_code::{parse_to_sparse_grid, Bounds, GridLike, Point, SparseGrid};
+use itertools::Itertools;
+
+advent_of_code::solution!(2023, 11);
+
+struct Universe {
+    grid: SparseGrid<char>,
+    x_empty: HashSet<i32>,
+    y_empty: HashSet<i32>,
+}
+
+fn calculate_distances(universe: Universe, expansion: i32) -> i64 {
+    let expanded: SparseGrid<char> = universe
+        .grid
+        .iter()
+        .map(|(Point { x, y }, c)| {
+            let x_exp = universe.x_empty.iter().filter(|v| x > **v).count() as i32;
+            let y_exp = universe.y_empty.iter().filter(|v| y > **v).count() as i32;
+
+            (
+                Point::new(x + x_exp * (expansion - 1), y + y_exp * (expansion - 1)),
+                *c,
+            )
+        })
+        .collect();
+
+    let result: i64 = expanded
+        .positions()
+        .tuple_combinations()
+        .map(|(a, b)| (a.x - b.x).abs() as i64 + (a.y - b.y).abs() as i64)
+        .sum();
+    result
+}
+
+fn parse_universe(input: &str) -> Universe {
+    let grid = parse_to_sparse_grid(input, |c| (c == '#').then_some(c));
+    let bounds = grid
+        .bounds()
+        .unwrap_or(Bounds::new(Point::new(0, 0), Point::new(0, 0)));
+
+    let x_set: HashSet<i32> = grid.positions().map(|p| p.x).collect();
+    let y_set: HashSet<i32> = grid.positions().map(|p| p.y).collect();
+
+    let x_empty = not_in_range(bounds.max.x, x_set);
+    let y_empty = not_in_range(bounds.max.y, y_set);
+
+    Universe {
+        grid,
+        x_empty,
+        y_empty,
+    }
+}
+
+fn not_in_range(size: i32, existing_set: HashSet<i32>) -> HashSet<i32> {
+    (0..=size).filter(|x| !existing_set.contains(x)).collect()
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    let universe = parse_universe(input);
+    let result = calculate_distances(universe, 2);
+    Some(result)
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let universe = parse_universe(input);
+    let result = calculate_distances(universe, 1_000_000);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(374));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(82000210));
+    }
+}
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use rayon::prelude::*;
 use memoize::memoize;
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 // fn get_groups(row: &[char]) -> Vec<u32> {
 //     row.iter()
@@ -146,13 +146,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(525152));
     }
 }
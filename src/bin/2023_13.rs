@@ -1,27 +1,13 @@
-use itertools::Itertools;
+use advent_of_code::{parse_to_vec_vec_grid, Grid};
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
-fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
-    assert!(!v.is_empty());
-    let len = v[0].len();
-    let mut iters: Vec<_> = v.into_iter().map(|n| n.into_iter()).collect();
-    (0..len)
-        .map(|_| {
-            iters
-                .iter_mut()
-                .map(|n| n.next().unwrap())
-                .collect::<Vec<T>>()
-        })
-        .collect()
-}
-
-fn find_mirror(grid: &[Vec<char>], end_condition: u32) -> u32 {
-    let width = grid[0].len();
+fn find_mirror(grid: &Grid<char>, end_condition: u32) -> u32 {
+    let width = grid.width;
 
     for x in 1..width {
         let max_range = x.min(width - x);
-        let differences = grid.iter().map(|row| {
+        let differences = grid.row_slices().map(|row| {
             let left = row[x - max_range..x].iter();
             let right = row[x..x + max_range].iter().rev();
             left.zip(right).map(|(a, b)| (a != b) as u32)
@@ -36,19 +22,12 @@ fn find_mirror(grid: &[Vec<char>], end_condition: u32) -> u32 {
     0
 }
 
-fn get_grid(block: &str) -> Vec<Vec<char>> {
-    let grid = block
-        .lines()
-        .map(|line| line.chars().collect_vec())
-        .collect_vec();
-    grid
-}
-fn test_for_mirrors(grid: Vec<Vec<char>>, end_condition: u32) -> u32 {
+fn test_for_mirrors(grid: Grid<char>, end_condition: u32) -> u32 {
     let vert = find_mirror(&grid, end_condition);
     if vert > 0 {
         vert
     } else {
-        find_mirror(&transpose(grid), end_condition) * 100
+        find_mirror(&grid.transpose(), end_condition) * 100
     }
 }
 
@@ -56,7 +35,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     let grids = input.trim().split("\n\n");
 
     let result = grids.map(|block| {
-        let grid = get_grid(block);
+        let grid = parse_to_vec_vec_grid(block, |c| c);
         test_for_mirrors(grid, 0)
     });
     Some(result.sum())
@@ -66,7 +45,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     let grids = input.trim().split("\n\n");
 
     let result = grids.map(|block| {
-        let grid = get_grid(block);
+        let grid = parse_to_vec_vec_grid(block, |c| c);
         test_for_mirrors(grid, 1)
     });
     Some(result.sum())
@@ -78,13 +57,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(405));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(400));
     }
 }
@@ -0,0 +1,67 @@
+use advent_of_code::cycle::nth_state;
+use advent_of_code::{parse_to_vec_vec_grid, Grid, Point};
+
+advent_of_code::solution!(2023, 14);
+
+/// Rolls every round rock as far north as it goes.
+fn tilt_north(grid: &mut Grid<char>) {
+    for x in 0..grid.width as i32 {
+        let mut free = 0;
+        for y in 0..grid.height as i32 {
+            match grid.get(&Point::new(x, y)) {
+                Some('#') => free = y + 1,
+                Some('O') => {
+                    grid.swap(&Point::new(x, y), &Point::new(x, free));
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east by tilting north and rotating clockwise four times.
+fn spin_cycle(grid: &Grid<char>) -> Grid<char> {
+    let mut grid = grid.clone();
+    for _ in 0..4 {
+        tilt_north(&mut grid);
+        grid = grid.rotate_clockwise();
+    }
+    grid
+}
+
+fn calc_load(grid: &Grid<char>) -> i32 {
+    grid.find_all(&'O')
+        .map(|position| grid.height as i32 - position.y)
+        .sum()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut grid = parse_to_vec_vec_grid(input, |c| c);
+    tilt_north(&mut grid);
+    let sum = calc_load(&grid);
+    Some(sum as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse_to_vec_vec_grid(input, |c| c);
+    let grid = nth_state(grid, 1_000_000_000, spin_cycle);
+    Some(calc_load(&grid) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(136));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
+        assert_eq!(result, Some(64));
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2023, 15);
 
 #[derive(Clone, Debug)]
 struct Lens<'a> {
@@ -98,13 +98,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(1320));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(145));
     }
 }
@@ -1,32 +1,9 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashSet, VecDeque};
 
-use advent_of_code::{parse_to_vec_vec_grid, vec_vec_grid_with_type, Grid, Point};
+use advent_of_code::{parse_to_vec_vec_grid, vec_vec_grid_with_type, Direction, Grid, Point};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-advent_of_code::solution!(16);
-
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn dir(&self) -> Point {
-        let map: HashMap<Direction, Point> = [
-            (Direction::Up, Point { x: 0, y: -1 }),
-            (Direction::Down, Point { x: 0, y: 1 }),
-            (Direction::Left, Point { x: -1, y: 0 }),
-            (Direction::Right, Point { x: 1, y: 0 }),
-        ]
-        .into_iter()
-        .collect();
-
-        map[self]
-    }
-}
+advent_of_code::solution!(2023, 16);
 
 #[derive(Clone)]
 struct Ray {
@@ -43,7 +20,7 @@ impl Ray {
     }
 
     fn step(&mut self) {
-        self.position += self.direction.dir();
+        self.position += self.direction.offset();
     }
 }
 
@@ -102,9 +79,8 @@ fn calculate_score(start_ray: Ray, grid: &Grid<Object>) -> usize {
         }
     }
     visited
-        .grid
+        .cells
         .iter()
-        .flatten()
         .filter(|hm| !hm.is_empty())
         .count()
 }
@@ -182,13 +158,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(46));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY).unwrap());
         assert_eq!(result, Some(51));
     }
 }
@@ -0,0 +1,122 @@
+/// Helpers for puzzles that apply the same step to a state an absurd number of times.
+///
+/// States are compared by value rather than by hash, so a collision can never produce a wrong answer.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cycle in a sequence of states: the state at index `start + length` equals the one at `start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Maps an arbitrarily large iteration number to the index of an equal state seen before the cycle repeated.
+    pub fn index_of(&self, iteration: usize) -> usize {
+        if iteration < self.start {
+            iteration
+        } else {
+            self.start + (iteration - self.start) % self.length
+        }
+    }
+}
+
+/// Applies `step` to `initial` until a state repeats.
+/// Returns every state up to the first repetition (`initial` included) and the detected cycle.
+///
+/// Never returns if the sequence of states does not repeat.
+pub fn find_cycle<S: Hash + Eq + Clone>(initial: S, step: impl FnMut(&S) -> S) -> (Vec<S>, Cycle) {
+    let (history, cycle) = run(initial, step, usize::MAX);
+    (history, cycle.expect("state space is too large to cycle"))
+}
+
+/// Returns the state after applying `step` to `initial` `iterations` times,
+/// skipping ahead as soon as the sequence of states starts repeating.
+pub fn nth_state<S: Hash + Eq + Clone>(
+    initial: S,
+    iterations: usize,
+    step: impl FnMut(&S) -> S,
+) -> S {
+    let (mut history, cycle) = run(initial, step, iterations);
+    let index = cycle.map_or(iterations, |cycle| cycle.index_of(iterations));
+    history.swap_remove(index)
+}
+
+/// Records states until one repeats or `limit` steps have been taken.
+fn run<S: Hash + Eq + Clone>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut history = vec![initial.clone()];
+    let mut seen = HashMap::from([(initial, 0)]);
+
+    while history.len() <= limit {
+        let next = step(history.last().unwrap());
+        let index = history.len();
+
+        if let Some(&start) = seen.get(&next) {
+            let cycle = Cycle {
+                start,
+                length: index - start,
+            };
+            return (history, Some(cycle));
+        }
+
+        seen.insert(next.clone(), index);
+        history.push(next);
+    }
+
+    (history, None)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_cycle, nth_state, Cycle};
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+    fn step(n: &u32) -> u32 {
+        if *n == 4 {
+            2
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn finds_cycle() {
+        let (history, cycle) = find_cycle(0, step);
+        assert_eq!(history, vec![0, 1, 2, 3, 4]);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                length: 3
+            }
+        );
+        assert_eq!(cycle.index_of(1), 1);
+        assert_eq!(cycle.index_of(5), 2);
+        assert_eq!(cycle.index_of(1_000_000_000), 4);
+    }
+
+    #[test]
+    fn nth_state_before_cycle() {
+        assert_eq!(nth_state(0, 0, step), 0);
+        assert_eq!(nth_state(0, 3, step), 3);
+    }
+
+    #[test]
+    fn nth_state_skips_cycles() {
+        let naive = |n: usize| (0..n).fold(0, |state, _| step(&state));
+        for n in [5, 6, 7, 100, 1001] {
+            assert_eq!(nth_state(0, n, step), naive(n));
+        }
+        assert_eq!(nth_state(0, 1_000_000_000, step), 4);
+    }
+
+    #[test]
+    fn nth_state_without_cycle() {
+        assert_eq!(nth_state(0_u64, 50, |n| n + 1), 50);
+    }
+}
@@ -0,0 +1,608 @@
+use std::ops::{Index, IndexMut};
+
+use itertools::Itertools;
+
+use crate::{Direction, Direction8, Point};
+
+const NEIGHBORS_4: [Point; 4] = [
+    Direction::Up.offset(),
+    Direction::Right.offset(),
+    Direction::Down.offset(),
+    Direction::Left.offset(),
+];
+
+const NEIGHBORS_8: [Point; 8] = [
+    Direction8::UpLeft.offset(),
+    Direction8::Up.offset(),
+    Direction8::UpRight.offset(),
+    Direction8::Right.offset(),
+    Direction8::DownRight.offset(),
+    Direction8::Down.offset(),
+    Direction8::DownLeft.offset(),
+    Direction8::Left.offset(),
+];
+
+/// The inclusive rectangle spanned by the cells of a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    pub fn contains(&self, position: &Point) -> bool {
+        position.x >= self.min.x
+            && position.x <= self.max.x
+            && position.y >= self.min.y
+            && position.y <= self.max.y
+    }
+
+    /// Returns the smallest bounds containing both `self` and `position`.
+    pub fn including(&self, position: &Point) -> Self {
+        Self {
+            min: Point::new(self.min.x.min(position.x), self.min.y.min(position.y)),
+            max: Point::new(self.max.x.max(position.x), self.max.y.max(position.y)),
+        }
+    }
+}
+
+/// Operations shared by the dense [`Grid`] and the [`SparseGrid`](crate::SparseGrid),
+/// so puzzle logic can be written once against either representation.
+pub trait GridLike<T> {
+    fn get(&self, position: &Point) -> Option<&T>;
+
+    /// Stores `value` at `position`.
+    /// Returns `false` if the grid cannot hold a value there.
+    fn set(&mut self, position: Point, value: T) -> bool;
+
+    /// The rectangle spanned by the grid, or [`None`] if it holds no cells.
+    fn bounds(&self) -> Option<Bounds>;
+
+    /// Iterates every cell holding a value.
+    fn occupied<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a;
+
+    /// Iterates the orthogonal neighbors of `position` holding a value, clockwise starting from up.
+    fn neighbors4<'a>(&'a self, position: &Point) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        neighbors_with(self, *position, &NEIGHBORS_4)
+    }
+
+    /// Iterates the orthogonal and diagonal neighbors of `position` holding a value, clockwise starting from the top left.
+    fn neighbors8<'a>(&'a self, position: &Point) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        neighbors_with(self, *position, &NEIGHBORS_8)
+    }
+}
+
+fn neighbors_with<'a, T: 'a, G: GridLike<T> + ?Sized>(
+    grid: &'a G,
+    position: Point,
+    offsets: &'static [Point],
+) -> impl Iterator<Item = (Point, &'a T)> {
+    offsets.iter().filter_map(move |offset| {
+        let neighbor = position + *offset;
+        grid.get(&neighbor).map(|value| (neighbor, value))
+    })
+}
+
+/// A dense, rectangular two-dimensional grid addressed by [`Point`].
+///
+/// `x` grows to the right and `y` grows downwards, matching the way puzzle inputs are read.
+///
+/// # Layout
+/// All values live in the single contiguous buffer `cells` in row-major order,
+/// i.e. the value at `(x, y)` is stored at `cells[y * width + x]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub cells: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from a row-major buffer.
+    ///
+    /// # Panics
+    /// Panics if `cells` does not hold exactly `width * height` values.
+    pub fn new(cells: Vec<T>, width: usize, height: usize) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "grid buffer does not match its {width}x{height} dimensions"
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid from a list of equally long rows.
+    ///
+    /// # Panics
+    /// Panics if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows differ in length"
+        );
+        Self::new(rows.into_iter().flatten().collect(), width, height)
+    }
+
+    /// Returns the buffer index of `position`, or [`None`] if it lies outside the grid.
+    pub fn index_of(&self, position: &Point) -> Option<usize> {
+        self.in_bounds(position)
+            .then(|| position.y as usize * self.width + position.x as usize)
+    }
+
+    /// Returns `true` if `position` lies inside the grid.
+    pub fn in_bounds(&self, position: &Point) -> bool {
+        position.x >= 0
+            && position.x < self.width as i32
+            && position.y >= 0
+            && position.y < self.height as i32
+    }
+
+    pub fn get(&self, position: &Point) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: &Point) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Swaps the values at `a` and `b`.
+    /// Returns `false` and leaves the grid untouched if either position is out of bounds.
+    pub fn swap(&mut self, a: &Point, b: &Point) -> bool {
+        match (self.index_of(a), self.index_of(b)) {
+            (Some(a), Some(b)) => {
+                self.cells.swap(a, b);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Iterates the in-bounds orthogonal neighbors of `position`, clockwise starting from up.
+    pub fn neighbors4<'a>(&'a self, position: &Point) -> impl Iterator<Item = (Point, &'a T)> {
+        neighbors_with(self, *position, &NEIGHBORS_4)
+    }
+
+    /// Iterates the in-bounds orthogonal and diagonal neighbors of `position`, clockwise starting from the top left.
+    pub fn neighbors8<'a>(&'a self, position: &Point) -> impl Iterator<Item = (Point, &'a T)> {
+        neighbors_with(self, *position, &NEIGHBORS_8)
+    }
+
+    /// Returns row `y` as a slice, or [`None`] if `y` is out of bounds.
+    pub fn row_slice(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterates all rows as slices from top to bottom.
+    pub fn row_slices(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// Iterates the values of row `y` from left to right. Empty if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.row_slice(y).into_iter().flatten()
+    }
+
+    /// Iterates the values of column `x` from top to bottom. Empty if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let values = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        values.iter().step_by(self.width.max(1))
+    }
+
+    /// Iterates all rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Iterates all columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates every position of the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        (0..self.height as i32)
+            .cartesian_product(0..self.width as i32)
+            .map(|(y, x)| Point::new(x, y))
+    }
+
+    /// Iterates every position of the grid together with its value in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Borrows the `width` x `height` rectangle whose top left corner is `origin` without copying it.
+    /// Returns [`None`] if the rectangle does not fit inside the grid.
+    pub fn view(&self, origin: Point, width: usize, height: usize) -> Option<GridView<'_, T>> {
+        let fits = self.in_bounds(&origin)
+            && origin.x as usize + width <= self.width
+            && origin.y as usize + height <= self.height;
+
+        fits.then_some(GridView {
+            grid: self,
+            origin,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> GridLike<T> for Grid<T> {
+    fn get(&self, position: &Point) -> Option<&T> {
+        Grid::get(self, position)
+    }
+
+    fn set(&mut self, position: Point, value: T) -> bool {
+        match self.get_mut(&position) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (self.width > 0 && self.height > 0).then(|| {
+            Bounds::new(
+                Point::new(0, 0),
+                Point::new(self.width as i32 - 1, self.height as i32 - 1),
+            )
+        })
+    }
+
+    fn occupied<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        self.enumerate()
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `position` lies outside the grid, use [`Grid::get`] for a checked lookup.
+    fn index(&self, position: Point) -> &Self::Output {
+        let i = self
+            .index_of(&position)
+            .unwrap_or_else(|| panic!("{position:?} is out of bounds"));
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    /// # Panics
+    /// Panics if `position` lies outside the grid, use [`Grid::get_mut`] for a checked lookup.
+    fn index_mut(&mut self, position: Point) -> &mut Self::Output {
+        let i = self
+            .index_of(&position)
+            .unwrap_or_else(|| panic!("{position:?} is out of bounds"));
+        &mut self.cells[i]
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Returns a copy of the grid mirrored along its main diagonal, i.e. rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+
+    /// Returns a copy of the grid rotated by 90 degrees clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height as i32;
+        self.remap(self.height, self.width, |p| {
+            Point::new(p.y, height - 1 - p.x)
+        })
+    }
+
+    /// Returns a copy of the grid rotated by 90 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let width = self.width as i32;
+        self.remap(self.height, self.width, |p| {
+            Point::new(width - 1 - p.y, p.x)
+        })
+    }
+
+    /// Returns a copy of the grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width as i32;
+        self.remap(self.width, self.height, |p| {
+            Point::new(width - 1 - p.x, p.y)
+        })
+    }
+
+    /// Returns a copy of the grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height as i32;
+        self.remap(self.width, self.height, |p| {
+            Point::new(p.x, height - 1 - p.y)
+        })
+    }
+
+    /// Builds a new `width` x `height` grid where every position takes the value found at `source(position)`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self {
+        let cells = (0..height as i32)
+            .cartesian_product(0..width as i32)
+            .map(|(y, x)| self[source(Point::new(x, y))].clone())
+            .collect_vec();
+        Grid::new(cells, width, height)
+    }
+}
+
+/// A borrowed rectangular window into a [`Grid`], addressed relative to its own top left corner.
+#[derive(Clone, Copy, Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn in_bounds(&self, position: &Point) -> bool {
+        position.x >= 0
+            && position.x < self.width as i32
+            && position.y >= 0
+            && position.y < self.height as i32
+    }
+
+    pub fn get(&self, position: &Point) -> Option<&'a T> {
+        if self.in_bounds(position) {
+            self.grid.get(&(self.origin + *position))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates the values of row `y` of the view from left to right.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &'a T> {
+        let (x, width) = (self.origin.x as usize, self.width);
+        let row = (y < self.height)
+            .then(|| self.grid.row_slice(self.origin.y as usize + y))
+            .flatten();
+        row.into_iter().flat_map(move |row| &row[x..x + width])
+    }
+
+    /// Iterates all rows of the view from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+}
+
+impl<'a, T: Clone> GridView<'a, T> {
+    /// Copies the viewed rectangle into an owned [`Grid`].
+    pub fn to_grid(&self) -> Grid<T> {
+        let cells = self.rows().flatten().cloned().collect_vec();
+        Grid::new(cells, self.width, self.height)
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns the first position in row-major order holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.find_all(value).next()
+    }
+
+    /// Iterates all positions holding `value` in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a {
+        self.enumerate()
+            .filter(move |(_, v)| *v == value)
+            .map(|(position, _)| position)
+    }
+}
+
+/// Parses a grid with one row per line, mapping every character through `f`.
+///
+/// Empty input yields an empty `0x0` grid.
+///
+/// # Panics
+/// Panics if the lines hold different numbers of characters.
+pub fn parse_to_vec_vec_grid<T>(input: &str, f: fn(char) -> T) -> Grid<T> {
+    let lines = input.trim().lines().collect_vec();
+
+    let height = lines.len();
+    let width = lines.first().map_or(0, |line| line.chars().count());
+    let mut cells = Vec::with_capacity(width * height);
+    for (y, line) in lines.into_iter().enumerate() {
+        let row_width = line.chars().count();
+        assert!(
+            row_width == width,
+            "grid row {y} is {row_width} characters wide, expected {width}"
+        );
+        cells.extend(line.chars().map(f));
+    }
+    Grid::new(cells, width, height)
+}
+
+pub fn vec_vec_grid_with_type<T: Default>(width: usize, height: usize) -> Grid<T> {
+    let cells = std::iter::repeat_with(T::default)
+        .take(width * height)
+        .collect_vec();
+    Grid::new(cells, width, height)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{parse_to_vec_vec_grid, Grid};
+    use crate::Point;
+
+    fn get_mock_grid() -> Grid<char> {
+        parse_to_vec_vec_grid("abc\ndef\nghi", |c| c)
+    }
+
+    #[test]
+    fn get_out_of_bounds() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(&Point::new(3, 1)), None);
+        assert_eq!(grid.get(&Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn neighbors4_in_corner() {
+        let grid = get_mock_grid();
+        let neighbors = grid.neighbors4(&Point::new(0, 0)).collect_vec();
+        assert_eq!(
+            neighbors,
+            vec![(Point::new(1, 0), &'b'), (Point::new(0, 1), &'d')]
+        );
+    }
+
+    #[test]
+    fn neighbors8_in_center() {
+        let grid = get_mock_grid();
+        let values: String = grid
+            .neighbors8(&Point::new(1, 1))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(values, "abcfihgd");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = get_mock_grid();
+        let rows = grid.rows().map(|r| r.collect::<String>()).collect_vec();
+        let columns = grid.columns().map(|c| c.collect::<String>()).collect_vec();
+        assert_eq!(rows, vec!["abc", "def", "ghi"]);
+        assert_eq!(columns, vec!["adg", "beh", "cfi"]);
+        assert_eq!(grid.row(3).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn positions_and_enumerate() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.positions().count(), 9);
+        assert_eq!(grid.positions().nth(4), Some(Point::new(1, 1)));
+        assert_eq!(grid.enumerate().nth(5), Some((Point::new(2, 1), &'f')));
+    }
+
+    #[test]
+    fn find_values() {
+        let grid = parse_to_vec_vec_grid("#.#\n..#", |c| c);
+        assert_eq!(grid.find(&'#'), Some(Point::new(0, 0)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect_vec(),
+            vec![Point::new(0, 0), Point::new(2, 0), Point::new(2, 1)]
+        );
+    }
+
+    fn to_strings(grid: &Grid<char>) -> Vec<String> {
+        grid.rows().map(|r| r.collect::<String>()).collect_vec()
+    }
+
+    #[test]
+    fn transpose() {
+        let grid = parse_to_vec_vec_grid("abc\ndef", |c| c);
+        assert_eq!(to_strings(&grid.transpose()), vec!["ad", "be", "cf"]);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn rotate() {
+        let grid = parse_to_vec_vec_grid("abc\ndef", |c| c);
+        assert_eq!(to_strings(&grid.rotate_clockwise()), vec!["da", "eb", "fc"]);
+        assert_eq!(
+            to_strings(&grid.rotate_counter_clockwise()),
+            vec!["cf", "be", "ad"]
+        );
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[test]
+    fn flip() {
+        let grid = parse_to_vec_vec_grid("abc\ndef", |c| c);
+        assert_eq!(to_strings(&grid.flip_horizontal()), vec!["cba", "fed"]);
+        assert_eq!(to_strings(&grid.flip_vertical()), vec!["def", "abc"]);
+    }
+
+    #[test]
+    fn view() {
+        let grid = get_mock_grid();
+        let view = grid.view(Point::new(1, 1), 2, 2).unwrap();
+        assert_eq!(view.get(&Point::new(0, 0)), Some(&'e'));
+        assert_eq!(view.get(&Point::new(1, 1)), Some(&'i'));
+        assert_eq!(view.get(&Point::new(2, 0)), None);
+        assert_eq!(to_strings(&view.to_grid()), vec!["ef", "hi"]);
+        assert!(grid.view(Point::new(2, 2), 2, 1).is_none());
+    }
+
+    #[test]
+    fn index_by_point() {
+        let mut grid = get_mock_grid();
+        assert_eq!(grid[Point::new(1, 2)], 'h');
+        grid[Point::new(1, 2)] = 'x';
+        assert_eq!(grid.cells.iter().collect::<String>(), "abcdefgxi");
+        assert_eq!(grid.index_of(&Point::new(2, 1)), Some(5));
+        assert_eq!(grid.row_slice(1), Some(&['d', 'e', 'f'][..]));
+    }
+
+    #[test]
+    fn parse_counts_characters() {
+        let grid = parse_to_vec_vec_grid("äö\n#.", |c| c);
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid[Point::new(1, 0)], 'ö');
+    }
+
+    #[test]
+    fn parse_empty_input() {
+        let grid = parse_to_vec_vec_grid("", |c| c);
+        assert_eq!((grid.width, grid.height), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "grid row 1 is 2 characters wide, expected 3")]
+    fn parse_ragged_rows() {
+        parse_to_vec_vec_grid("abc\nde", |c| c);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let grid = get_mock_grid();
+        let _ = grid[Point::new(3, 0)];
+    }
+
+    #[test]
+    fn swap_values() {
+        let mut grid = get_mock_grid();
+        assert!(grid.swap(&Point::new(0, 0), &Point::new(2, 2)));
+        assert!(grid.swap(&Point::new(1, 1), &Point::new(2, 1)));
+        assert!(!grid.swap(&Point::new(0, 0), &Point::new(3, 0)));
+        assert_eq!(to_strings(&grid), vec!["ibc", "dfe", "gha"]);
+    }
+}
@@ -0,0 +1,301 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+/// A half-open range of integers `start..end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the interval of `len` integers beginning at `start`.
+    pub fn from_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The integers contained in both intervals, possibly empty.
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Splits into the parts below and from `at` on, either of which may be empty.
+    pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Self::new(self.start, at), Self::new(at, self.end))
+    }
+
+    /// Moves the interval by `offset`.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of integers stored as sorted, disjoint and non-adjacent [`Interval`]s.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The normalized intervals making up the set, in ascending order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        *self = self.union(&Self::from_iter([interval]));
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.intervals
+            .iter()
+            .chain(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        self.intervals
+            .iter()
+            .cartesian_product(other.intervals.iter())
+            .map(|(a, b)| a.intersection(b))
+            .collect()
+    }
+
+    /// The integers in `self` that are not in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut remaining = self.intervals.clone();
+        for cut in &other.intervals {
+            remaining = remaining
+                .into_iter()
+                .flat_map(|interval| {
+                    let (below, rest) = interval.split_at(cut.start);
+                    let (_, above) = rest.split_at(cut.end);
+                    [below, above]
+                })
+                .collect();
+        }
+        remaining.into_iter().collect()
+    }
+
+    /// Splits into the integers below and from `at` on.
+    pub fn split_at(&self, at: i64) -> (RangeSet, RangeSet) {
+        let (below, above): (Vec<_>, Vec<_>) = self
+            .intervals
+            .iter()
+            .map(|interval| interval.split_at(at))
+            .unzip();
+        (below.into_iter().collect(), above.into_iter().collect())
+    }
+
+    /// Moves every integer of the set by `offset`.
+    pub fn shift(&self, offset: i64) -> RangeSet {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.shift(offset)).collect(),
+        }
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    /// Collects intervals in any order, dropping empty ones and merging overlapping or adjacent ones.
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval> = Vec::new();
+
+        for interval in iter.into_iter().filter(|i| !i.is_empty()).sorted() {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+
+        Self { intervals }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A piecewise mapping that shifts every value inside a source interval by that interval's offset
+/// and leaves values outside of all source intervals unchanged.
+///
+/// If source intervals overlap, the one inserted first wins.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalMap {
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl IntervalMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shifts the values in `source` by `offset`.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        self.pieces.push((source, offset));
+    }
+
+    /// Maps a single value.
+    pub fn map_value(&self, value: i64) -> i64 {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    /// Maps every value of `set` at once, splitting its intervals wherever pieces begin or end.
+    pub fn map_set(&self, set: &RangeSet) -> RangeSet {
+        let mut remaining = set.clone();
+        let mut mapped = RangeSet::new();
+
+        for (source, offset) in &self.pieces {
+            let source = RangeSet::from_iter([*source]);
+            mapped = mapped.union(&remaining.intersection(&source).shift(*offset));
+            remaining = remaining.difference(&source);
+        }
+
+        mapped.union(&remaining)
+    }
+}
+
+impl FromIterator<(Interval, i64)> for IntervalMap {
+    fn from_iter<I: IntoIterator<Item = (Interval, i64)>>(iter: I) -> Self {
+        Self {
+            pieces: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Interval, IntervalMap, RangeSet};
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|(start, end)| Interval::new(*start, *end))
+            .collect()
+    }
+
+    #[test]
+    fn interval_basics() {
+        let a = Interval::new(2, 8);
+        assert_eq!(a.len(), 6);
+        assert!(a.contains(2));
+        assert!(!a.contains(8));
+        assert_eq!(a.intersection(&Interval::new(5, 20)), Interval::new(5, 8));
+        assert!(!a.overlaps(&Interval::new(8, 9)));
+        assert_eq!(a.split_at(4), (Interval::new(2, 4), Interval::new(4, 8)));
+        assert!(a.split_at(0).0.is_empty());
+        assert_eq!(a.shift(-2), Interval::from_len(0, 6));
+    }
+
+    #[test]
+    fn normalizes_on_collect() {
+        let s = set(&[(10, 12), (0, 3), (3, 5), (4, 6), (20, 20)]);
+        assert_eq!(s, set(&[(0, 6), (10, 12)]));
+        assert_eq!(s.len(), 8);
+        assert_eq!(s.min(), Some(0));
+        assert_eq!(s.max(), Some(11));
+        assert!(s.contains(5));
+        assert!(!s.contains(6));
+        assert!(s.contains(10));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert_eq!(
+            a.split_at(22),
+            (set(&[(0, 10), (20, 22)]), set(&[(22, 30)]))
+        );
+        assert_eq!(a.shift(5), set(&[(5, 15), (25, 35)]));
+
+        let mut c = RangeSet::new();
+        c.insert(Interval::new(0, 2));
+        c.insert(Interval::new(2, 4));
+        assert_eq!(c, set(&[(0, 4)]));
+    }
+
+    #[test]
+    fn maps_whole_sets() {
+        // day 5's seed-to-soil map: 98..100 -> 50..52, 50..98 -> 52..100
+        let map: IntervalMap = [
+            (Interval::from_len(98, 2), 50 - 98),
+            (Interval::from_len(50, 48), 52 - 50),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(map.map_value(79), 81);
+        assert_eq!(map.map_value(99), 51);
+        assert_eq!(map.map_value(10), 10);
+
+        let seeds = set(&[(40, 60), (95, 105)]);
+        let mapped = map.map_set(&seeds);
+        assert_eq!(mapped.len(), seeds.len());
+        for value in [40, 49, 50, 59, 95, 99, 100, 104] {
+            assert!(mapped.contains(map.map_value(value)));
+        }
+        assert_eq!(mapped, set(&[(40, 62), (97, 105)]));
+    }
+}
@@ -1,55 +1,136 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, compare, download, examples, read, record, scaffold, solve,
+};
+use advent_of_code::template::runner::RunOptions;
 use args::{parse, AppArguments};
 
+/// Every solution in `src/bin`, collected by the build script.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
+    use std::ffi::OsString;
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::get_year;
+    use advent_of_code::template::input::InputSource;
+    use advent_of_code::{Day, Year};
 
     pub enum AppArguments {
         Download {
+            year: Year,
+            day: Day,
+        },
+        Examples {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
+            day: Day,
+        },
+        Record {
+            year: Year,
             day: Day,
         },
         Scaffold {
+            year: Year,
             day: Day,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             time: bool,
+            budget: Option<f64>,
+            timeout: Option<f64>,
+            memory: bool,
             submit: Option<u8>,
+            verify: bool,
+            input: InputSource,
         },
         All {
+            year: Year,
             release: bool,
             time: bool,
+            budget: Option<f64>,
+            timeout: Option<f64>,
+            memory: bool,
+            parallel: bool,
+            verify: bool,
+        },
+        Compare {
+            year: Year,
+            baseline: Option<String>,
+            threshold: Option<f64>,
         },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        let mut raw_args: Vec<OsString> = std::env::args_os().skip(1).collect();
+        let example = take_example(&mut raw_args);
+        let mut args = pico_args::Arguments::from_vec(raw_args);
+
+        let subcommand = args.subcommand()?;
+        // every command works on a year, the day is parsed after it to check that the year has that day.
+        let year = parse_year(&mut args)?;
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                budget: args.opt_value_from_str("--budget")?,
+                timeout: args.opt_value_from_str("--timeout")?,
+                memory: args.contains("--memory"),
+                parallel: args.contains("--parallel"),
+                verify: args.contains("--verify"),
+            },
+            Some("compare") => AppArguments::Compare {
+                year,
+                baseline: args.opt_value_from_str("--baseline")?,
+                threshold: args.opt_value_from_str("--threshold")?,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                year,
+                day: parse_day(&mut args, year)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                year,
+                day: parse_day(&mut args, year)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                year,
+                day: parse_day(&mut args, year)?,
+            },
+            Some("record") => AppArguments::Record {
+                year,
+                day: parse_day(&mut args, year)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                year,
+                day: parse_day(&mut args, year)?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                year,
+                day: parse_day(&mut args, year)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
+                budget: args.opt_value_from_str("--budget")?,
+                timeout: args.opt_value_from_str("--timeout")?,
+                memory: args.contains("--memory"),
+                verify: args.contains("--verify"),
+                input: match (args.opt_value_from_str::<_, String>("--input")?, example) {
+                    (None, None) => InputSource::Puzzle,
+                    (Some(path), None) => InputSource::from_path(&path),
+                    (None, Some(n)) => InputSource::Example(n),
+                    (Some(_), Some(_)) => {
+                        eprintln!("--input and --example cannot be used together.");
+                        process::exit(1);
+                    }
+                },
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -68,6 +149,140 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// The year given with `--year`, defaulting to `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => get_year().ok_or_else(|| {
+                "no year given, pass --year or set AOC_YEAR in \".cargo/config.toml\".".into()
+            }),
+        }
+    }
+
+    /// The day passed to a command, which has to be part of the event of `year`.
+    fn parse_day(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Day, Box<dyn std::error::Error>> {
+        let day: Day = args.free_from_str()?;
+        if !year.has_day(day) {
+            return Err(format!(
+                "the event of {year} has no day {}, its last day is {}.",
+                day.into_inner(),
+                year.last_day()
+            )
+            .into());
+        }
+        Ok(day)
+    }
+
+    /// Options that take a value, which must not be mistaken for the day.
+    const VALUE_OPTIONS: [&str; 7] = [
+        "--year",
+        "--submit",
+        "--budget",
+        "--timeout",
+        "--input",
+        "--baseline",
+        "--threshold",
+    ];
+
+    /// Removes `--example [N]` from `args`, as pico-args does not support options with an optional value.
+    ///
+    /// A number after `--example` is only taken as `N` if the day came before it,
+    /// so `solve --example 5` runs the example of day 5 and `solve 5 --example 2` its second example.
+    fn take_example(args: &mut Vec<OsString>) -> Option<Option<u8>> {
+        let index = args.iter().position(|x| x == "--example")?;
+        args.remove(index);
+
+        let number = has_day(&args[..index])
+            .then(|| args.get(index))
+            .flatten()
+            .and_then(|x| x.to_str())
+            .and_then(|x| x.parse::<u8>().ok());
+        if number.is_some() {
+            args.remove(index);
+        }
+
+        Some(number)
+    }
+
+    /// Whether `args`, which start with the subcommand, hold a free argument besides it.
+    fn has_day(args: &[OsString]) -> bool {
+        let mut rest = args.iter().skip(1);
+        while let Some(arg) = rest.next() {
+            match arg.to_str() {
+                Some(option) if VALUE_OPTIONS.contains(&option) => {
+                    rest.next();
+                }
+                Some(option) if option.starts_with("--") => {}
+                _ => return true,
+            }
+        }
+        false
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::ffi::OsString;
+
+        use super::take_example;
+
+        fn take(args: &str) -> (Option<Option<u8>>, Vec<OsString>) {
+            let mut args = args.split(' ').map(OsString::from).collect();
+            let example = take_example(&mut args);
+            (example, args)
+        }
+
+        #[test]
+        fn test_example_after_day() {
+            assert_eq!(
+                take("solve 5 --example 2"),
+                (Some(Some(2)), vec!["solve".into(), "5".into()])
+            );
+            assert_eq!(
+                take("solve 5 --example"),
+                (Some(None), vec!["solve".into(), "5".into()])
+            );
+            assert_eq!(
+                take("solve --year 2022 5 --example 2"),
+                (
+                    Some(Some(2)),
+                    vec!["solve".into(), "--year".into(), "2022".into(), "5".into()]
+                )
+            );
+        }
+
+        #[test]
+        fn test_example_before_day() {
+            assert_eq!(
+                take("solve --example 5"),
+                (Some(None), vec!["solve".into(), "5".into()])
+            );
+            assert_eq!(
+                take("solve --year 2022 --example 5 --time"),
+                (
+                    Some(None),
+                    vec![
+                        "solve".into(),
+                        "--year".into(),
+                        "2022".into(),
+                        "5".into(),
+                        "--time".into()
+                    ]
+                )
+            );
+            assert_eq!(take("solve 5"), (None, vec!["solve".into(), "5".into()]));
+        }
+    }
+}
+
+fn run_options(time: bool, budget: Option<f64>, timeout: Option<f64>, memory: bool) -> RunOptions {
+    RunOptions::new(time, budget, timeout, memory).unwrap_or_else(|format| {
+        eprintln!("Unexpected command-line input. Format: {format}");
+        std::process::exit(1);
+    })
 }
 
 fn main() {
@@ -77,16 +292,53 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                time,
+                budget,
+                timeout,
+                memory,
+                parallel,
+                verify,
+            } => all::handle(
+                solutions::SOLUTIONS,
+                year,
+                release,
+                run_options(time, budget, timeout, memory),
+                parallel,
+                verify,
+            ),
+            AppArguments::Compare {
+                year,
+                baseline,
+                threshold,
+            } => compare::handle(year, baseline.as_deref(), threshold),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Examples { year, day } => examples::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Record { year, day } => record::handle(solutions::SOLUTIONS, year, day),
+            AppArguments::Scaffold { year, day } => scaffold::handle(year, day),
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 time,
+                budget,
+                timeout,
+                memory,
+                submit,
+                verify,
+                input,
+            } => solve::handle(
+                year,
+                day,
+                release,
+                run_options(time, budget, timeout, memory),
                 submit,
-            } => solve::handle(day, release, time, submit),
+                verify,
+                &input,
+            ),
         },
     };
 }
@@ -0,0 +1,232 @@
+/// Exact integer number theory and sequence helpers.
+use itertools::Itertools;
+
+/// Greatest common divisor, `gcd(0, 0)` is `0`.
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple, `lcm(x, 0)` is `0`.
+/// Returns [`None`] if the result does not fit into an [`u64`].
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        Some(0)
+    } else {
+        (a / gcd(a, b)).checked_mul(b)
+    }
+}
+
+/// Greatest common divisor of all values, `0` for an empty slice.
+pub fn gcd_all(values: &[u64]) -> u64 {
+    values.iter().fold(0, |acc, v| gcd(acc, *v))
+}
+
+/// Least common multiple of all values, `1` for an empty slice.
+/// Returns [`None`] if the result does not fit into an [`u64`].
+pub fn lcm_all(values: &[u64]) -> Option<u64> {
+    values.iter().try_fold(1, |acc, v| lcm(acc, *v))
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+        (g, y, x - a.div_euclid(b) * y)
+    }
+}
+
+/// Chinese Remainder Theorem: finds the smallest non-negative `x` with `x ≡ remainder (mod modulus)`
+/// for every `(remainder, modulus)` pair, returned together with the combined modulus.
+///
+/// Moduli do not need to be coprime, negative moduli are treated like their absolute value.
+/// Returns [`None`] if the congruences contradict each other, if a modulus is zero,
+/// or if the combined modulus does not fit into an [`i64`].
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (x, modulus) = congruences
+        .iter()
+        .try_fold((0_i128, 1_i128), |(x, m), &(r, n)| {
+            let n = i128::from(n).abs();
+            if n == 0 {
+                return None;
+            }
+            let (g, p, _) = extended_gcd(m, n);
+            let diff = i128::from(r) - x;
+            if diff % g != 0 {
+                return None;
+            }
+            // keep every intermediate modulus within i64, so the products below fit into i128.
+            let combined = m / g * n;
+            i64::try_from(combined).ok()?;
+            let step = (diff / g).checked_mul(p)?.rem_euclid(n / g);
+            Some(((x + m * step).rem_euclid(combined), combined))
+        })?;
+
+    Some((i64::try_from(x).ok()?, i64::try_from(modulus).ok()?))
+}
+
+/// The integer square root, i.e. the largest `r` with `r * r <= n`.
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // the float estimate is off by at most one for large inputs, correct it exactly.
+    let mut r = (n as f64).sqrt() as u64;
+    while r.checked_mul(r).is_none_or(|sq| sq > n) {
+        r -= 1;
+    }
+    while (r + 1).checked_mul(r + 1).is_some_and(|sq| sq <= n) {
+        r += 1;
+    }
+    r
+}
+
+/// Counts the integers `x` with `a * x² + b * x + c < 0`, for `a > 0`.
+///
+/// # Panics
+/// Panics if `a` is not positive, as the count would be infinite,
+/// or if the discriminant `b² - 4ac` does not fit into an [`u64`].
+pub fn count_negative_quadratic(a: i64, b: i64, c: i64) -> u64 {
+    assert!(a > 0, "leading coefficient must be positive");
+    let (a, b, c) = (a as i128, b as i128, c as i128);
+    let f = |x: i128| a * x * x + b * x + c;
+
+    // the polynomial is lowest at the vertex, if it is not negative at the integers next to it, it never is.
+    let vertex = (-b).div_euclid(2 * a);
+    let Some(inside) = [vertex, vertex + 1].into_iter().find(|x| f(*x) < 0) else {
+        return 0;
+    };
+
+    // start from the roots rounded through the integer square root and nudge them onto the exact bounds.
+    let discriminant = u64::try_from(b * b - 4 * a * c).expect("discriminant is too large");
+    let root = isqrt(discriminant) as i128;
+    let mut low = (-b - root).div_euclid(2 * a).min(inside);
+    let mut high = (-b + root).div_euclid(2 * a).max(inside);
+
+    while f(low) >= 0 {
+        low += 1;
+    }
+    while f(low - 1) < 0 {
+        low -= 1;
+    }
+    while f(high) >= 0 {
+        high -= 1;
+    }
+    while f(high + 1) < 0 {
+        high += 1;
+    }
+
+    (high - low + 1) as u64
+}
+
+/// Repeatedly takes differences between neighboring values until they are all zero.
+/// The first row is `values` itself.
+pub fn difference_table(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut table = vec![values.to_vec()];
+    while table.last().unwrap().iter().any(|v| *v != 0) {
+        let next = table
+            .last()
+            .unwrap()
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| b - a)
+            .collect_vec();
+        table.push(next);
+    }
+    table
+}
+
+/// Predicts the value following `values` via Newton's forward differences.
+pub fn extrapolate_forward(values: &[i64]) -> i64 {
+    difference_table(values)
+        .iter()
+        .filter_map(|row| row.last())
+        .sum()
+}
+
+/// Predicts the value preceding `values` via Newton's backward differences.
+pub fn extrapolate_backward(values: &[i64]) -> i64 {
+    difference_table(values)
+        .iter()
+        .rev()
+        .filter_map(|row| row.first())
+        .fold(0, |prev, first| first - prev)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        count_negative_quadratic, crt, extrapolate_backward, extrapolate_forward, gcd, gcd_all,
+        isqrt, lcm, lcm_all,
+    };
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(gcd_all(&[12, 18, 30]), 6);
+        // not squarefree, collecting distinct prime factors would yield 6.
+        assert_eq!(lcm_all(&[4, 6, 9]), Some(36));
+        assert_eq!(lcm_all(&[]), Some(1));
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(
+            lcm_all(&[1 << 32, (1 << 32) - 1]),
+            Some(u64::MAX - (1 << 32) + 1)
+        );
+        assert_eq!(lcm_all(&[1 << 32, (1 << 32) - 1, 7]), None);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 4), (0, 0)]), None);
+        assert_eq!(crt(&[(0, i64::MAX), (1, i64::MAX - 1)]), None);
+    }
+
+    #[test]
+    fn integer_square_root() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX), 4294967295);
+        assert_eq!(isqrt(999_999_999_999_999_999), 999_999_999);
+    }
+
+    #[test]
+    fn quadratic_roots() {
+        let naive = |t: i64, d: i64| (0..=t).filter(|x| x * (t - x) > d).count() as u64;
+        for (t, d) in [(7, 9), (15, 40), (30, 200), (10, 25), (10, 24), (4, 100)] {
+            assert_eq!(
+                count_negative_quadratic(1, -t, d),
+                naive(t, d),
+                "t={t} d={d}"
+            );
+        }
+        assert_eq!(count_negative_quadratic(1, -71530, 940200), 71503);
+    }
+
+    #[test]
+    fn extrapolation() {
+        assert_eq!(extrapolate_forward(&[0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(extrapolate_forward(&[10, 13, 16, 21, 30, 45]), 68);
+        assert_eq!(extrapolate_backward(&[10, 13, 16, 21, 30, 45]), 5);
+        assert_eq!(extrapolate_backward(&[1, 3, 6, 10, 15, 21]), 0);
+    }
+}
@@ -0,0 +1,279 @@
+/// Reusable nom combinators for the shapes puzzle inputs come in.
+///
+/// Combinators are plain nom parsers and compose with everything in [`nom`].
+/// Run the outermost one through [`parse`] to require the whole input to be consumed
+/// and to turn failures into a [`ParseError`] pointing at the offending line and column.
+use std::fmt::Display;
+
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::{char, i64, line_ending, space0, space1},
+    combinator::{eof, value},
+    error::{Error, ErrorKind},
+    multi::{many1, separated_list1},
+    sequence::{pair, separated_pair, terminated},
+    IResult, Parser,
+};
+
+use crate::Grid;
+
+/// The reason a [`ParseError`] occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A combinator did not match, carrying what nom was trying to parse.
+    Unexpected(ErrorKind),
+    /// The parser succeeded but did not consume the whole input.
+    TrailingInput,
+    /// The input ended while the parser still expected more.
+    UnexpectedEnd,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::Unexpected(kind) => {
+                write!(f, "unexpected input, expected {}", kind.description())
+            }
+            ParseErrorKind::TrailingInput => write!(f, "unexpected trailing input"),
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input"),
+        }
+    }
+}
+
+/// A parse failure, `line` and `column` are 1-based and count characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Locates `rest`, a suffix of `input`, within `input`.
+    fn at(input: &str, rest: &str, kind: ParseErrorKind) -> Self {
+        let consumed = &input[..input.len() - rest.len()];
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on `input` without its trailing whitespace and requires it to consume everything.
+pub fn parse<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> Result<O, ParseError> {
+    let input = input.trim_end();
+    match parser.parse(input) {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(ParseError::at(input, rest, ParseErrorKind::TrailingInput)),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at(
+            input,
+            e.input,
+            ParseErrorKind::Unexpected(e.code),
+        )),
+        Err(nom::Err::Incomplete(_)) => {
+            Err(ParseError::at(input, "", ParseErrorKind::UnexpectedEnd))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One or more signed integers separated by spaces, e.g. `79 -14  55`.
+pub fn int_list(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list1(space1, i64)(input)
+}
+
+/// One or more items separated by commas and optional spaces, e.g. `3 blue, 4 red`.
+pub fn comma_list<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// A `key: value` pair, any spaces after the colon are skipped.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, Error<&'a str>>,
+    value: impl Parser<&'a str, V, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, pair(char(':'), space0), value)
+}
+
+/// A line holding nothing but a label and a colon, e.g. `seed-to-soil map:`. Returns the label.
+pub fn label(input: &str) -> IResult<&str, &str> {
+    terminated(
+        take_while1(|c| c != ':' && c != '\n'),
+        pair(char(':'), alt((line_ending, eof))),
+    )(input)
+}
+
+/// One or more lines, each parsed by `item` in full.
+pub fn lines<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, item)
+}
+
+/// A [`label`] followed by the lines below it, parsed by `item`.
+pub fn section<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, Vec<O>)> {
+    pair(label, lines(item))
+}
+
+/// The end of a line followed by one or more empty lines.
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    value((), pair(line_ending, many1(line_ending)))(input)
+}
+
+/// One or more blocks separated by blank lines, each parsed by `item`.
+pub fn blocks<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(blank_line, item)
+}
+
+/// A rectangle of characters, one row per line, with every cell converted by `f`.
+///
+/// Fails with [`ErrorKind::Verify`] at the first row whose width differs from the first one.
+pub fn char_grid<'a, T>(f: impl Fn(char) -> T) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    move |input| {
+        let (rest, rows) = lines(take_while1(|c| c != '\n' && c != '\r'))(input)?;
+
+        let width = rows[0].chars().count();
+        if let Some(row) = rows.iter().find(|row| row.chars().count() != width) {
+            return Err(nom::Err::Error(Error::new(row, ErrorKind::Verify)));
+        }
+
+        let cells = rows
+            .iter()
+            .flat_map(|row| row.chars().map(&f))
+            .collect_vec();
+        Ok((rest, Grid::new(cells, width, rows.len())))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{alpha1, u32},
+        error::ErrorKind,
+        sequence::{preceded, separated_pair},
+    };
+
+    use super::{
+        blocks, char_grid, comma_list, int_list, key_value, label, lines, parse, section,
+        ParseError, ParseErrorKind,
+    };
+    use crate::Point;
+
+    #[test]
+    fn integer_lists() {
+        assert_eq!(int_list("79 -14  55 13"), Ok(("", vec![79, -14, 55, 13])));
+        assert_eq!(
+            parse("1 2\n3\n", lines(int_list)),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            comma_list(alpha1)("a, b,c rest"),
+            Ok((" rest", vec!["a", "b", "c"]))
+        );
+    }
+
+    #[test]
+    fn keys_and_labels() {
+        let game = key_value(preceded(tag("Game "), u32), comma_list(int_list));
+        assert_eq!(
+            parse("Game 7: 1 2, 3", game),
+            Ok((7, vec![vec![1, 2], vec![3]]))
+        );
+        assert_eq!(
+            parse("Time:      7  15   30", key_value(alpha1, int_list)),
+            Ok(("Time", vec![7, 15, 30]))
+        );
+
+        assert_eq!(label("seed-to-soil map:"), Ok(("", "seed-to-soil map")));
+        assert!(label("seeds: 79 14").is_err());
+    }
+
+    #[test]
+    fn sections_and_blocks() {
+        let input = "seeds: 79 14\n\na map:\n50 98 2\n52 50 48\n\n\nb map:\n0 15 37\n";
+        let (seeds, maps) = parse(
+            input,
+            separated_pair(
+                key_value(tag("seeds"), int_list),
+                super::blank_line,
+                blocks(section(int_list)),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(seeds, ("seeds", vec![79, 14]));
+        assert_eq!(
+            maps,
+            vec![
+                ("a map", vec![vec![50, 98, 2], vec![52, 50, 48]]),
+                ("b map", vec![vec![0, 15, 37]]),
+            ]
+        );
+    }
+
+    #[test]
+    fn char_grids() {
+        let grids = parse("#.\n.#\n\n...\n", blocks(char_grid(|c| c == '#'))).unwrap();
+        assert_eq!(grids.len(), 2);
+        assert_eq!((grids[0].width, grids[0].height), (2, 2));
+        assert!(grids[0][Point::new(1, 1)]);
+        assert_eq!((grids[1].width, grids[1].height), (3, 1));
+
+        assert_eq!(
+            parse("##\n#\n", char_grid(|c| c)).unwrap_err(),
+            ParseError {
+                line: 2,
+                column: 1,
+                kind: ParseErrorKind::Unexpected(ErrorKind::Verify),
+            }
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_failure() {
+        let error = parse("1 2\n3 x\n", lines(int_list)).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 2,
+                kind: ParseErrorKind::TrailingInput,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected trailing input"
+        );
+
+        let error = parse("a map:\nx", section(int_list)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.kind, ParseErrorKind::Unexpected(ErrorKind::Digit));
+    }
+}
@@ -0,0 +1,280 @@
+/// Graph searches over implicit graphs.
+///
+/// A graph is described by a start state and a successor closure, so any `Hash + Eq` value can be a node:
+/// a [`Point`](crate::Point) on a [`Grid`](crate::Grid), a `(Point, Direction)` pair, or a whole puzzle state.
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The outcome of a search: the cost to reach every visited state and how it was reached.
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    /// The lowest known cost from the start to every visited state.
+    pub distances: HashMap<S, C>,
+    /// The state each visited state was reached from. The start has no entry.
+    pub predecessors: HashMap<S, S>,
+    /// The goal state the search stopped at, if any.
+    pub goal: Option<S>,
+}
+
+impl<S: Hash + Eq + Clone, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Self {
+            distances: HashMap::from([(start, zero)]),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// Reconstructs the path from the start to `target`, both included.
+    /// Returns [`None`] if `target` was not reached.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(previous) = self.predecessors.get(current) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The cost of the goal the search stopped at.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// The path to the goal the search stopped at.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Breadth-first search where every step costs `1`.
+///
+/// Stops as soon as a state satisfying `is_goal` is reached, pass `|_| false` to explore everything reachable.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let distance = search.distances[&state];
+        for next in successors(&state) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), distance + 1);
+            search.predecessors.insert(next.clone(), state.clone());
+            queue.push_back(next);
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's shortest path search, `successors` yields every neighbor together with the cost of the step.
+///
+/// Stops as soon as a state satisfying `is_goal` is settled, pass `|_| false` to explore everything reachable.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to the closest goal.
+///
+/// Stops as soon as a state satisfying `is_goal` is settled, pass `|_| false` to explore everything reachable.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+
+    while let Some(Entry { cost, state, .. }) = queue.pop() {
+        // skip stale queue entries for states that were reached more cheaply in the meantime.
+        if search
+            .distances
+            .get(&state)
+            .is_some_and(|best| cost > *best)
+        {
+            continue;
+        }
+
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|best| next_cost >= *best)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), state.clone());
+            queue.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    search
+}
+
+/// A queue entry ordered so that [`BinaryHeap`] pops the lowest priority first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::{parse_to_vec_vec_grid, Grid, Point};
+
+    fn get_mock_maze() -> Grid<char> {
+        parse_to_vec_vec_grid("S..#\n.#.#\n.#..\n...E", |c| c)
+    }
+
+    fn open_neighbors(grid: &Grid<char>, p: &Point) -> Vec<Point> {
+        grid.neighbors4(p)
+            .filter(|(_, c)| **c != '#')
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_on_grid() {
+        let grid = get_mock_maze();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let search = bfs(start, |p| open_neighbors(&grid, p), |p| *p == end);
+        assert_eq!(search.goal, Some(end));
+        assert_eq!(search.goal_distance(), Some(6));
+
+        let path = search.goal_path().unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+        assert!(path.windows(2).all(|w| w[0].manhattan_distance(&w[1]) == 1));
+    }
+
+    #[test]
+    fn bfs_explores_everything() {
+        let grid = get_mock_maze();
+        let search = bfs(Point::new(0, 0), |p| open_neighbors(&grid, p), |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.distances.len(), 12);
+        assert_eq!(search.distance(&Point::new(3, 2)), Some(5));
+        assert_eq!(search.distance(&Point::new(1, 1)), None);
+        assert_eq!(search.path_to(&Point::new(1, 1)), None);
+        assert_eq!(
+            search.path_to(&Point::new(0, 0)),
+            Some(vec![Point::new(0, 0)])
+        );
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_detour() {
+        // 0 -> 1 costs 10, 0 -> 2 -> 1 costs 3.
+        let edges = |n: &u8| match n {
+            0 => vec![(1, 10), (2, 1)],
+            2 => vec![(1, 2)],
+            1 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let search = dijkstra(0_u8, edges, |n| *n == 3);
+        assert_eq!(search.goal_distance(), Some(4_u32));
+        assert_eq!(search.goal_path(), Some(vec![0, 2, 1, 3]));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_on_weighted_grid() {
+        let grid = parse_to_vec_vec_grid("1163\n1381\n2136\n3694", |c| c.to_digit(10).unwrap());
+        let end = Point::new(3, 3);
+        let successors = |p: &Point| {
+            grid.neighbors4(p)
+                .map(|(p, cost)| (p, *cost))
+                .collect::<Vec<_>>()
+        };
+
+        let plain = dijkstra(Point::new(0, 0), successors, |p| *p == end);
+        let guided = astar(
+            Point::new(0, 0),
+            successors,
+            |p| p.manhattan_distance(&end),
+            |p| *p == end,
+        );
+        assert_eq!(plain.goal_distance(), Some(17));
+        assert_eq!(guided.goal_distance(), plain.goal_distance());
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use crate::{Bounds, GridLike, Point};
+
+/// An unbounded two-dimensional grid that only stores occupied cells.
+///
+/// The bounding box of all cells ever inserted is tracked as cells come and go,
+/// so puzzles can still iterate "the whole grid" when it is mostly empty or grows in any direction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: &Point) -> bool {
+        self.cells.contains_key(position)
+    }
+
+    pub fn get(&self, position: &Point) -> Option<&T> {
+        self.cells.get(position)
+    }
+
+    pub fn get_mut(&mut self, position: &Point) -> Option<&mut T> {
+        self.cells.get_mut(position)
+    }
+
+    /// Stores `value` at `position`, growing the bounds if needed, and returns the previous value.
+    pub fn insert(&mut self, position: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(&position),
+            None => Bounds::new(position, position),
+        });
+        self.cells.insert(position, value)
+    }
+
+    /// Removes and returns the value at `position`.
+    /// Shrinks the bounds if the removed cell was on their edge, which costs a scan over all cells.
+    pub fn remove(&mut self, position: &Point) -> Option<T> {
+        let value = self.cells.remove(position)?;
+
+        let on_edge = self.bounds.is_some_and(|b| {
+            position.x == b.min.x
+                || position.x == b.max.x
+                || position.y == b.min.y
+                || position.y == b.max.y
+        });
+        if on_edge {
+            self.bounds = Self::compute_bounds(self.cells.keys());
+        }
+        Some(value)
+    }
+
+    /// Iterates every occupied cell in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .map(|(position, value)| (*position, value))
+    }
+
+    /// Iterates every occupied position in arbitrary order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + Clone + '_ {
+        self.cells.keys().copied()
+    }
+
+    fn compute_bounds<'a>(mut positions: impl Iterator<Item = &'a Point>) -> Option<Bounds> {
+        let first = *positions.next()?;
+        Some(
+            positions.fold(Bounds::new(first, first), |bounds, position| {
+                bounds.including(position)
+            }),
+        )
+    }
+}
+
+impl<T> GridLike<T> for SparseGrid<T> {
+    fn get(&self, position: &Point) -> Option<&T> {
+        self.cells.get(position)
+    }
+
+    fn set(&mut self, position: Point, value: T) -> bool {
+        self.insert(position, value);
+        true
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    fn occupied<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (position, value) in iter {
+            grid.insert(position, value);
+        }
+        grid
+    }
+}
+
+/// Parses a character grid into a [`SparseGrid`], keeping only the cells for which `f` returns a value.
+pub fn parse_to_sparse_grid<T>(input: &str, f: fn(char) -> Option<T>) -> SparseGrid<T> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter_map(move |(x, c)| f(c).map(|value| (Point::new(x as i32, y as i32), value)))
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{parse_to_sparse_grid, SparseGrid};
+    use crate::{parse_to_vec_vec_grid, Bounds, GridLike, Point};
+
+    #[test]
+    fn tracks_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Point::new(2, 3), 'a');
+        grid.insert(Point::new(-5, 10), 'b');
+        grid.insert(Point::new(0, 0), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds::new(Point::new(-5, 0), Point::new(2, 10)))
+        );
+
+        grid.remove(&Point::new(-5, 10));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds::new(Point::new(0, 0), Point::new(2, 3)))
+        );
+
+        grid.remove(&Point::new(2, 3));
+        grid.remove(&Point::new(0, 0));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn parses_occupied_cells() {
+        let grid = parse_to_sparse_grid("#..\n..#\n...", |c| (c == '#').then_some(c));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&'#'));
+        assert_eq!(grid.get(&Point::new(1, 1)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds::new(Point::new(0, 0), Point::new(2, 1)))
+        );
+    }
+
+    /// Counts the occupied neighbors of every occupied cell, written once for both grid types.
+    fn neighbor_counts(grid: &impl GridLike<char>) -> Vec<(Point, usize)> {
+        grid.occupied()
+            .filter(|(_, c)| **c == '#')
+            .map(|(p, _)| (p, grid.neighbors8(&p).filter(|(_, c)| **c == '#').count()))
+            .sorted_by_key(|(p, _)| (p.y, p.x))
+            .collect()
+    }
+
+    #[test]
+    fn shares_logic_with_dense_grid() {
+        let input = "##.\n.#.\n..#";
+        let dense = parse_to_vec_vec_grid(input, |c| c);
+        let sparse = parse_to_sparse_grid(input, |c| (c == '#').then_some(c));
+
+        assert_eq!(neighbor_counts(&dense), neighbor_counts(&sparse));
+        assert_eq!(dense.bounds().map(|b| b.width()), Some(3));
+        assert_eq!(sparse.bounds().map(|b| b.height()), Some(3));
+    }
+}
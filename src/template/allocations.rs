@@ -0,0 +1,184 @@
+/// Module that counts heap allocations through a global allocator wrapping [`System`].
+///
+/// Counting is opt-in through the `count_allocations` feature, as every allocation pays for two atomic additions.
+/// The counters cover the whole process, allocations made on other threads while a part runs are included.
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "count_allocations")]
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// The number and total size of heap allocations made over some span of time.
+/// Growing an allocation in place or by moving it counts as a new allocation of its new size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+    #[serde(rename = "allocations")]
+    pub count: u64,
+    #[serde(rename = "allocated_bytes")]
+    pub bytes: u64,
+}
+
+impl Allocations {
+    /// The allocations made by the process so far, [`None`] if they are not counted.
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        #[cfg(feature = "count_allocations")]
+        return Some(Self {
+            count: COUNT.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+        });
+
+        #[cfg(not(feature = "count_allocations"))]
+        None
+    }
+
+    /// The allocations made since `earlier`, which was taken from [`Allocations::current`] before.
+    #[must_use]
+    pub fn since(&self, earlier: &Allocations) -> Allocations {
+        Allocations {
+            count: self.count.saturating_sub(earlier.count),
+            bytes: self.bytes.saturating_sub(earlier.bytes),
+        }
+    }
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = if self.count == 1 {
+            "allocation"
+        } else {
+            "allocations"
+        };
+        write!(f, "{} {unit}, {}", self.count, format_bytes(self.bytes))
+    }
+}
+
+impl std::ops::Add for Allocations {
+    type Output = Allocations;
+
+    fn add(self, rhs: Allocations) -> Allocations {
+        Allocations {
+            count: self.count + rhs.count,
+            bytes: self.bytes + rhs.bytes,
+        }
+    }
+}
+
+/// Formats a number of bytes with a binary prefix, e.g. `1.5 MiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "count_allocations")]
+static COUNT: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "count_allocations")]
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "count_allocations")]
+struct CountingAllocator;
+
+#[cfg(feature = "count_allocations")]
+impl CountingAllocator {
+    fn count(size: usize) {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "count_allocations")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[cfg(feature = "count_allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, Allocations};
+
+    #[test]
+    fn formats_allocations() {
+        let earlier = Allocations {
+            count: 10,
+            bytes: 100,
+        };
+        let later = Allocations {
+            count: 13,
+            bytes: 1636,
+        };
+        assert_eq!(
+            later.since(&earlier),
+            Allocations {
+                count: 3,
+                bytes: 1536
+            }
+        );
+        assert_eq!(later.since(&earlier).to_string(), "3 allocations, 1.5 KiB");
+        assert_eq!(
+            (Allocations { count: 1, bytes: 8 }).to_string(),
+            "1 allocation, 8 B"
+        );
+        assert_eq!(
+            earlier + later,
+            Allocations {
+                count: 23,
+                bytes: 1736
+            }
+        );
+
+        assert_eq!(format_bytes(1440 * 1024), "1.4 MiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+
+    #[cfg(feature = "count_allocations")]
+    #[test]
+    fn counts_allocations() {
+        let before = Allocations::current().unwrap();
+        let values: Vec<u64> = Vec::with_capacity(64);
+        let after = Allocations::current().unwrap();
+        drop(values);
+
+        let made = after.since(&before);
+        assert!(made.count >= 1);
+        assert!(made.bytes >= 64 * 8);
+    }
+}
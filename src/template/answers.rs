@@ -0,0 +1,198 @@
+/// Module that stores the known correct answers of each day in `data/<year>/answers/DD.txt`.
+/// Every solved part is one line of the form `<part>: <answer>`, where line breaks and backslashes
+/// in the answer are escaped as `\n`, `\r` and `\\`.
+use std::fmt::Display;
+use std::{fs, io, path::PathBuf};
+
+use crate::template::get_data_path;
+use crate::{Day, Year};
+
+/// The recorded answers of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+/// The result of checking an answer against the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: String },
+    Unrecorded,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✔"),
+            Verdict::Incorrect { expected } => write!(f, "✘ (expected {expected})"),
+            Verdict::Unrecorded => write!(f, "? (no recorded answer)"),
+        }
+    }
+}
+
+#[must_use]
+pub fn get_answers_path(year: Year, day: Day) -> PathBuf {
+    get_data_path(year, "answers", &format!("{day}.txt"))
+}
+
+impl Answers {
+    /// Reads the answers recorded for `day`, which are empty if none were recorded yet.
+    pub fn read(year: Year, day: Day) -> io::Result<Self> {
+        match fs::read_to_string(get_answers_path(year, day)) {
+            Ok(content) => Ok(Self::parse(&content)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn write(&self, year: Year, day: Day) -> io::Result<()> {
+        let path = get_answers_path(year, day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    fn parse(content: &str) -> Self {
+        let mut answers = Self::default();
+        for line in content.lines() {
+            let Some((part, answer)) = line.split_once(':') else {
+                continue;
+            };
+            if let Ok(part @ 1..=2) = part.trim().parse::<u8>() {
+                answers.set(part, &unescape(answer.trim()));
+            }
+        }
+        answers
+    }
+
+    /// The recorded answer of `part`, which is either `1` or `2`.
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    /// Records the answer of `part`.
+    ///
+    /// # Panics
+    /// Panics if `part` is not `1` or `2`.
+    pub fn set(&mut self, part: u8, answer: &str) {
+        assert!(part == 1 || part == 2, "there are only two parts");
+        self.parts[usize::from(part) - 1] = Some(answer.to_string());
+    }
+
+    /// Checks `answer` against the one recorded for `part`.
+    /// Not having an answer counts as incorrect once one was recorded.
+    pub fn verify(&self, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unrecorded,
+            Some(expected) if answer == Some(expected) => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, answer) in self.parts.iter().enumerate() {
+            if let Some(answer) = answer {
+                writeln!(f, "{}: {}", i + 1, escape(answer))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Escapes an answer so that it fits on a single line.
+fn escape(answer: &str) -> String {
+    answer
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Reverts [`escape`], leaving unknown escape sequences as they are.
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('\\') => unescaped.push('\\'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Adds `answer` to the answers recorded for `day`, replacing a previous answer of `part`.
+pub fn record(year: Year, day: Day, part: u8, answer: &str) -> io::Result<()> {
+    let mut answers = Answers::read(year, day)?;
+    answers.set(part, answer);
+    answers.write(year, day)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn parses_and_formats() {
+        let answers = Answers::parse("1: 35\n2:  46 \n\n3: 7\nnonsense\n");
+        assert_eq!(answers.get(1), Some("35"));
+        assert_eq!(answers.get(2), Some("46"));
+        assert_eq!(answers.get(0), None);
+        assert_eq!(answers.get(3), None);
+        assert_eq!(answers.to_string(), "1: 35\n2: 46\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn formats_partial_answers() {
+        let mut answers = Answers::default();
+        answers.set(2, "abc");
+        assert_eq!(answers.to_string(), "2: abc\n");
+        assert_eq!(Answers::parse("").get(1), None);
+    }
+
+    #[test]
+    fn escapes_multi_line_answers() {
+        let mut answers = Answers::default();
+        answers.set(1, "#..#\n#\\.#");
+        answers.set(2, "a\\nb");
+        assert_eq!(answers.to_string(), "1: #..#\\n#\\\\.#\n2: a\\\\nb\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers::parse("1: 35\n");
+        assert_eq!(answers.verify(1, Some("35")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, Some("36")),
+            Verdict::Incorrect {
+                expected: "35".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(1, None),
+            Verdict::Incorrect {
+                expected: "35".into()
+            }
+        );
+        assert_eq!(answers.verify(2, Some("46")), Verdict::Unrecorded);
+    }
+}
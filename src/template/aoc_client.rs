@@ -0,0 +1,345 @@
+/// Module that talks to the Advent of Code website to download inputs and puzzles and to submit answers.
+///
+/// Requests are authenticated with the `session` cookie of a logged-in browser, see [`read_session`].
+/// The base URL can be changed with `AOC_BASE_URL` to run against a local stand-in server.
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs, io};
+
+use crate::template::get_data_path;
+use crate::template::puzzle::{get_puzzle_path, html_to_markdown};
+use crate::template::submissions::SubmissionOutcome;
+use crate::{Day, Year};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "github.com/fspoettel/advent-of-code-rust/",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    /// The server answered with an error status, e.g. 404 for a puzzle that is not unlocked yet.
+    BadStatus {
+        url: String,
+        status: u16,
+    },
+    Transport(String),
+    /// The body of a response could not be read, e.g. because it was cut off or is not valid UTF-8.
+    Response(io::Error),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or paste it into \"~/.config/adventofcode.session\"."
+            ),
+            AocClientError::BadStatus { url, status: 400 | 500 } => write!(
+                f,
+                "request to {url} was rejected, your session cookie may have expired."
+            ),
+            AocClientError::BadStatus { url, status: 404 } => {
+                write!(f, "{url} was not found, the puzzle may not be unlocked yet.")
+            }
+            AocClientError::BadStatus { url, status } => {
+                write!(f, "request to {url} failed with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach the server: {e}"),
+            AocClientError::Response(e) => write!(f, "could not read the server's response: {e}"),
+            AocClientError::IoError(e) => write!(f, "could not write output files: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AocClientError {}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::BadStatus {
+                url: response.get_url().to_string(),
+                status,
+            },
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str, year: Year) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    /// Configures the client for the puzzles of `year` from `AOC_SESSION` or the session file and `AOC_BASE_URL`.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the puzzle page of a day as HTML, which contains part two once part one is solved.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&self.day_url(day))
+    }
+
+    /// Submits an answer and parses the response into a [`SubmissionOutcome`].
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, AocClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()
+            .map_err(AocClientError::Response)?;
+
+        // the response is a whole page, only its <article> holds the message about the answer.
+        let article = match (html.find("<article"), html.rfind("</article>")) {
+            (Some(start), Some(end)) if start < end => &html[start..end],
+            _ => &html,
+        };
+        Ok(SubmissionOutcome::parse(&html_to_markdown(article)))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let body = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()
+            .map_err(AocClientError::Response)?;
+        Ok(body)
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+}
+
+/// Downloads the input and puzzle of a day to `data/<year>/inputs/DD.txt` and `data/<year>/puzzles/DD.md`.
+pub fn download(client: &AocClient, day: Day) -> Result<(), AocClientError> {
+    let input_path = get_input_path(client.year, day);
+    write_file(&input_path, &client.input(day)?)?;
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    download_puzzle(client, day)
+}
+
+/// Downloads the puzzle of a day to `data/<year>/puzzles/DD.md`, converted to Markdown.
+pub fn download_puzzle(client: &AocClient, day: Day) -> Result<(), AocClientError> {
+    let puzzle_path = get_puzzle_path(client.year, day);
+    write_file(&puzzle_path, &html_to_markdown(&client.puzzle(day)?))?;
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+/// Reads the session cookie from `AOC_SESSION`, `~/.config/adventofcode.session` or,
+/// as used by aoc-cli, `~/.adventofcode.session`.
+#[must_use]
+pub fn read_session() -> Option<String> {
+    let from_env = env::var("AOC_SESSION").ok();
+    let from_file = || {
+        session_paths()
+            .iter()
+            .find_map(|path| fs::read_to_string(path).ok())
+    };
+
+    from_env
+        .or_else(from_file)
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+fn session_paths() -> Vec<PathBuf> {
+    let Some(home) = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) else {
+        return vec![];
+    };
+    let home = PathBuf::from(home);
+    vec![
+        home.join(".config").join("adventofcode.session"),
+        home.join(".adventofcode.session"),
+    ]
+}
+
+fn get_input_path(year: Year, day: Day) -> PathBuf {
+    get_data_path(year, "inputs", &format!("{day}.txt"))
+}
+
+fn write_file(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::{AocClient, AocClientError};
+    use crate::template::submissions::SubmissionOutcome;
+    use crate::{day, year};
+
+    /// Serves a single request with `status` and `body`, returning the request it received.
+    fn serve_once(status: u16, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        serve_response(format!(
+            "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        ))
+    }
+
+    /// Answers a single request with the raw `response` and returns the request it received.
+    fn serve_response(response: String) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut form = vec![0; content_length];
+            reader.read_exact(&mut form).unwrap();
+            request.push_str(&String::from_utf8(form).unwrap());
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, server) = serve_once(200, "1abc2\npqr3stu8vwx\n");
+        let client = AocClient::new(&base_url, "abc123", year!(2023));
+
+        let input = client.input(day!(1)).unwrap();
+        assert_eq!(input, "1abc2\npqr3stu8vwx\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let html = "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>";
+        let (base_url, server) = serve_once(200, html);
+        let client = AocClient::new(&format!("{base_url}/"), "abc123", year!(2023));
+
+        let outcome = client.submit(day!(12), 2, "525152").unwrap();
+        assert_eq!(outcome, SubmissionOutcome::Correct);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/12/answer HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=525152"));
+    }
+
+    #[test]
+    fn keeps_only_the_article_of_unknown_responses() {
+        let html = "<html><head><script>var x;</script></head><body><header>Advent of Code [Log Out]</header><main><article><p>Something <em>unexpected</em>.</p></article></main><footer>Sponsors</footer></body></html>";
+        let (base_url, server) = serve_once(200, html);
+        let client = AocClient::new(&format!("{base_url}/"), "abc123", year!(2023));
+
+        let outcome = client.submit(day!(12), 1, "42").unwrap();
+        assert_eq!(
+            outcome,
+            SubmissionOutcome::Unknown {
+                message: "Something *unexpected*.".into()
+            }
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_truncated_responses() {
+        let (base_url, server) = serve_response(
+            "HTTP/1.1 200 OK\r\nContent-Length: 100\r\nConnection: close\r\n\r\n1abc2".into(),
+        );
+        let client = AocClient::new(&base_url, "abc123", year!(2023));
+
+        let error = client.input(day!(1)).unwrap_err();
+        assert!(matches!(error, AocClientError::Response(_)));
+        assert!(error
+            .to_string()
+            .starts_with("could not read the server's response"));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, server) = serve_once(404, "Not Found");
+        let client = AocClient::new(&base_url, "abc123", year!(2023));
+
+        let error = client.puzzle(day!(25)).unwrap_err();
+        assert!(matches!(
+            error,
+            AocClientError::BadStatus { status: 404, .. }
+        ));
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,287 @@
+/// Module that keeps the results of every benchmark run in `data/benchmarks/history.jsonl`,
+/// one JSON object per run, so later runs can be compared against earlier ones.
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::project_root;
+use crate::template::runner::Report;
+use crate::{Day, Year};
+
+/// The timings of a single benchmark run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The year that was benchmarked, [`None`] for runs recorded before the history kept track of it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<Year>,
+    /// The abbreviated hash of the checked out commit, if in a git repository.
+    pub commit: Option<String>,
+    pub timings: Vec<PartTiming>,
+}
+
+/// The median duration of a parse step or solution part.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub day: Day,
+    /// The solution part, [`None`] for the parse step.
+    pub part: Option<u8>,
+    pub duration_ns: u64,
+}
+
+impl PartTiming {
+    fn label(&self) -> String {
+        match self.part {
+            Some(part) => format!("Day {} Part {part}", self.day),
+            None => format!("Day {} Parse", self.day),
+        }
+    }
+}
+
+impl Run {
+    /// Creates a run of `year` from the reports of solved parts, stamped with the current time and commit.
+    #[must_use]
+    pub fn from_reports(year: Year, reports: &[Report]) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        let timings = reports
+            .iter()
+            .filter(|r| r.part.is_none() || r.answer.is_some())
+            .map(|r| PartTiming {
+                day: r.day,
+                part: r.part,
+                #[allow(clippy::cast_possible_truncation)]
+                duration_ns: r.stats.median.as_nanos() as u64,
+            })
+            .collect();
+
+        Self {
+            timestamp,
+            year: Some(year),
+            commit: current_commit(),
+            timings,
+        }
+    }
+
+    /// A short description of when and on which commit the run happened.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let date = format_date(self.timestamp);
+        match &self.commit {
+            Some(commit) => format!("{commit} ({date})"),
+            None => date,
+        }
+    }
+}
+
+#[must_use]
+pub fn get_history_path() -> PathBuf {
+    project_root()
+        .join("data")
+        .join("benchmarks")
+        .join("history.jsonl")
+}
+
+/// Appends a run to the history.
+pub fn append(run: &Run) -> io::Result<()> {
+    let path = get_history_path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(run).map_err(io::Error::other)?;
+    writeln!(file, "{line}")
+}
+
+/// Reads every run of the history, oldest first. A missing history is empty.
+pub fn read() -> io::Result<Vec<Run>> {
+    let file = match fs::File::open(get_history_path()) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    BufReader::new(file)
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |l| !l.trim().is_empty()))
+        .map(|line| serde_json::from_str(&line?).map_err(io::Error::other))
+        .collect()
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+/// Formats seconds since the unix epoch as an UTC `YYYY-MM-DD` date.
+fn format_date(timestamp: u64) -> String {
+    // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How the timing of a parse step or part changed between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub label: String,
+    pub baseline_ns: Option<u64>,
+    pub latest_ns: Option<u64>,
+}
+
+impl Comparison {
+    /// The change from the baseline in percent, positive if the latest run is slower.
+    pub fn change(&self) -> Option<f64> {
+        match (self.baseline_ns, self.latest_ns) {
+            (Some(baseline), Some(latest)) if baseline > 0 => {
+                Some((latest as f64 - baseline as f64) / baseline as f64 * 100_f64)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the latest run is slower than the baseline by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// Pairs up the timings of both runs, in the order of the latest run followed by timings only the baseline has.
+#[must_use]
+pub fn compare(baseline: &Run, latest: &Run) -> Vec<Comparison> {
+    let find = |run: &Run, timing: &PartTiming| {
+        run.timings
+            .iter()
+            .find(|t| t.day == timing.day && t.part == timing.part)
+            .map(|t| t.duration_ns)
+    };
+
+    let mut comparisons: Vec<Comparison> = latest
+        .timings
+        .iter()
+        .map(|timing| Comparison {
+            label: timing.label(),
+            baseline_ns: find(baseline, timing),
+            latest_ns: Some(timing.duration_ns),
+        })
+        .collect();
+
+    comparisons.extend(
+        baseline
+            .timings
+            .iter()
+            .filter(|timing| find(latest, timing).is_none())
+            .map(|timing| Comparison {
+                label: timing.label(),
+                baseline_ns: Some(timing.duration_ns),
+                latest_ns: None,
+            }),
+    );
+
+    comparisons
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, format_date, PartTiming, Run};
+    use crate::{day, year};
+
+    fn run(commit: &str, timings: &[(u8, Option<u8>, u64)]) -> Run {
+        Run {
+            timestamp: 0,
+            year: None,
+            commit: Some(commit.into()),
+            timings: timings
+                .iter()
+                .map(|(d, part, duration_ns)| PartTiming {
+                    day: crate::Day::new(*d).unwrap(),
+                    part: *part,
+                    duration_ns: *duration_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_701_388_800), "2023-12-01");
+    }
+
+    #[test]
+    fn compares_runs() {
+        let baseline = run(
+            "a",
+            &[(5, None, 100), (5, Some(1), 1000), (16, Some(2), 200)],
+        );
+        let latest = run("b", &[(5, None, 100), (5, Some(1), 1300), (6, Some(1), 50)]);
+
+        let comparisons = compare(&baseline, &latest);
+        let labels: Vec<_> = comparisons.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            [
+                "Day 05 Parse",
+                "Day 05 Part 1",
+                "Day 06 Part 1",
+                "Day 16 Part 2"
+            ]
+        );
+
+        assert_eq!(comparisons[0].change(), Some(0_f64));
+        assert_eq!(comparisons[1].change(), Some(30_f64));
+        assert!(comparisons[1].is_regression(10_f64));
+        assert!(!comparisons[1].is_regression(30_f64));
+        assert_eq!(comparisons[2].change(), None);
+        assert_eq!(comparisons[3].latest_ns, None);
+        assert!(!comparisons[3].is_regression(0_f64));
+    }
+
+    #[test]
+    fn serializes_runs() {
+        let run = run("abc1234", &[(1, Some(2), 42)]);
+        let json = serde_json::to_string(&run).unwrap();
+        assert_eq!(
+            json,
+            r#"{"timestamp":0,"commit":"abc1234","timings":[{"day":1,"part":2,"duration_ns":42}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Run>(&json).unwrap(), run);
+        assert_eq!(run.timings[0].day, day!(1));
+
+        let run = Run {
+            year: Some(year!(2023)),
+            ..run
+        };
+        let json = serde_json::to_string(&run).unwrap();
+        assert!(json.starts_with(r#"{"timestamp":0,"year":2023,"commit":"abc1234""#));
+        assert_eq!(serde_json::from_str::<Run>(&json).unwrap(), run);
+    }
+}
@@ -1,15 +1,39 @@
-use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::template::{
+    answers::Verdict,
+    benchmark_history::{self, Run},
+    read_file,
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    runner::{
+        exit_if_regressed, print_read_error, print_report, verify_report, Report, RunOptions,
+    },
+    Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Day, Year};
+
+/// Runs every registered solution of `year` in this process, one day after another or all at once if `is_parallel` is set.
+/// With `is_verify`, answers are checked against the recorded ones and any difference exits with a non-zero status.
+/// With `is_release`, the timings are written to the README and the benchmark history,
+/// unless this is a debug build, the days ran in parallel or a part timed out.
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    is_release: bool,
+    options: RunOptions,
+    is_parallel: bool,
+    is_verify: bool,
+) {
+    let is_timed = options.bench.is_some();
 
-pub fn handle(is_release: bool, is_timed: bool) {
     let mut timings: Vec<Timings> = vec![];
+    let mut timed_reports: Vec<Report> = vec![];
+    let mut verdicts: Vec<Verdict> = vec![];
 
-    all_days().for_each(|day| {
+    let mut print_day = |day: Day, reports: Option<Vec<Report>>| {
         if day > 1 {
             println!();
         }
@@ -17,238 +41,218 @@ pub fn handle(is_release: bool, is_timed: bool) {
         println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
         println!("------");
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        match reports {
+            Some(reports) => {
+                for report in &reports {
+                    let verdict =
+                        (is_verify && report.part.is_some()).then(|| verify_report(report));
+                    print_report(report, verdict.as_ref());
+                    verdicts.extend(verdict);
+                }
+                if is_timed {
+                    timings.push(collect_timings(&reports, year, day));
+                    timed_reports.extend(reports);
+                }
+            }
+            None => println!("Not solved."),
+        }
+    };
+
+    if is_parallel {
+        let results: Vec<_> = year
+            .days()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|day| (day, run_day(solutions, year, day, &options)))
+            .collect();
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+        for (day, reports) in results {
+            print_day(day, reports);
+        }
+    } else {
+        for day in year.days() {
+            print_day(day, run_day(solutions, year, day, &options));
         }
-    });
+    }
+
+    if is_verify {
+        let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+        println!(
+            "\n{ANSI_BOLD}Verified:{ANSI_RESET} {} ✔, {} ✘, {} not recorded",
+            count(|v| *v == Verdict::Correct),
+            count(|v| matches!(v, Verdict::Incorrect { .. })),
+            count(|v| *v == Verdict::Unrecorded),
+        );
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
+        // a part that timed out keeps running on its own thread and competes with every day after it.
+        let has_timeouts = timed_reports.iter().any(|report| report.timed_out);
+        if has_timeouts {
+            eprintln!("Warning: parts that timed out kept running in the background, the timings of the days after them are skewed.");
+        }
+
+        if is_release && cfg!(debug_assertions) {
+            eprintln!("Not storing benchmarks of a debug build, run `cargo all --release --time` to take them with an optimized build.");
+        } else if is_release && is_parallel {
+            // concurrent days skew each other's timings and share the process-wide allocation and memory counters.
+            eprintln!("Not storing benchmarks taken with --parallel, run the days one after another to store them.");
+        } else if is_release && has_timeouts {
+            eprintln!("Not storing benchmarks of a run in which parts timed out.");
+        } else if is_release {
+            match readme_benchmarks::update(year, timings, total_millis) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
-                }
+                Err(e) => eprintln!("Failed to update readme with benchmarks: {e}"),
+            }
+
+            match benchmark_history::append(&Run::from_reports(year, &timed_reports)) {
+                Ok(()) => println!("Successfully added benchmarks to the history."),
+                Err(e) => eprintln!("Failed to add benchmarks to the history: {e}"),
             }
         }
     }
-}
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
+    exit_if_regressed();
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
+/// Runs the solution for `day` of `year` on its input.
+/// Returns [`None`] if the day has no solution yet, its input is missing or it panicked.
+pub fn run_day(
+    solutions: &[Solution],
+    year: Year,
+    day: Day,
+    options: &RunOptions,
+) -> Option<Vec<Report>> {
+    let solution = solutions
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)?;
+    let input = match read_file("inputs", year, day) {
+        Ok(input) => Arc::from(input),
+        Err(e) => {
+            print_read_error(&e);
+            return None;
+        }
+    };
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    // the panic message is printed by the default hook, a failing day must not stop the others.
+    panic::catch_unwind(AssertUnwindSafe(|| (solution.run)(input, options))).ok()
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+/// Summarizes the median durations of a day's parse step and parts for the benchmark table.
+fn collect_timings(reports: &[Report], year: Year, day: Day) -> Timings {
+    let mut timings = Timings {
+        year,
+        day,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+        allocations: None,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
-
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    // unsolved parts show up as "-" rather than with the time it took to give up.
+    for report in reports
+        .iter()
+        .filter(|r| r.part.is_none() || r.answer.is_some() || r.timed_out)
+    {
+        let median = report.stats.median;
+        let timing = if report.timed_out {
+            Some("timed out".into())
+        } else {
+            Some(format!("{median:.1?}"))
+        };
 
-        if is_release {
-            args.push("--release");
+        match report.part {
+            None => timings.parse = timing,
+            Some(1) => timings.part_1 = timing,
+            Some(2) => timings.part_2 = timing,
+            Some(_) => continue,
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if let Some(allocations) = report.allocations {
+            timings.allocations = Some(timings.allocations.unwrap_or_default() + allocations);
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
-
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
-
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        if report.timed_out {
+            continue;
         }
-
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
+        timings.total_nanos += median.as_nanos() as f64;
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
+    timings
+}
 
-        timings
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::collect_timings;
+    use crate::template::runner::{BenchStats, Report};
+    use crate::{day, year};
+
+    fn report(part: Option<u8>, nanos: u64, samples: usize) -> Report {
+        let samples = vec![Duration::from_nanos(nanos); samples];
+        Report {
+            year: year!(2023),
+            day: day!(1),
+            part,
+            answer: part.map(|part| part.to_string()),
+            stats: BenchStats::from_samples(&samples),
+            timed_out: false,
+            peak_memory: None,
+            allocations: None,
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    #[test]
+    fn test_well_formed() {
+        let res = collect_timings(
+            &[report(Some(1), 74, 100000), report(Some(2), 74_130_000, 99)],
+            year!(2023),
+            day!(1),
+        );
+        assert_eq!(res.total_nanos, 74130074_f64);
+        assert_eq!(res.parse, None);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn test_parse_timing() {
+        let res = collect_timings(
+            &[report(None, 1500, 200), report(Some(1), 2_000_000_000, 5)],
+            year!(2023),
+            day!(1),
+        );
+        assert_eq!(res.total_nanos, 2000001500_f64);
+        assert_eq!(res.parse.unwrap(), "1.5µs");
+        assert_eq!(res.part_1.unwrap(), "2.0s");
+        assert_eq!(res.part_2, None);
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_missing_parts() {
+        let mut unsolved = report(Some(1), 100, 1);
+        unsolved.answer = None;
+
+        let res = collect_timings(&[unsolved], year!(2023), day!(1));
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn test_timed_out_parts() {
+        let mut timed_out = report(Some(2), 10_000_000_000, 1);
+        timed_out.answer = None;
+        timed_out.timed_out = true;
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
-
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+        let res = collect_timings(&[report(Some(1), 500, 1), timed_out], year!(2023), day!(1));
+        assert_eq!(res.total_nanos, 500_f64);
+        assert_eq!(res.part_2.unwrap(), "timed out");
     }
 }
@@ -0,0 +1,100 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::benchmark_history::{self, compare, Run};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::Year;
+
+/// Regressions of up to this many percent are treated as noise.
+const DEFAULT_THRESHOLD: f64 = 10_f64;
+
+/// Compares the latest benchmark run of `year` against `baseline`, the most recent earlier run on a commit starting with it,
+/// or the run before the latest one. Exits with a non-zero status if any timing got slower by more than `threshold` percent.
+/// Runs recorded before the history kept track of years are compared with every year.
+pub fn handle(year: Year, baseline: Option<&str>, threshold: Option<f64>) {
+    let threshold = threshold.unwrap_or(DEFAULT_THRESHOLD);
+    if !threshold.is_finite() || threshold < 0_f64 {
+        eprintln!("Unexpected command-line input. Format: --threshold <percent>");
+        process::exit(1);
+    }
+
+    let history: Vec<Run> = match benchmark_history::read() {
+        Ok(history) => history
+            .into_iter()
+            .filter(|run| run.year.is_none_or(|y| y == year))
+            .collect(),
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    let Some((latest, earlier)) = history.split_last() else {
+        eprintln!(
+            "No benchmarks recorded for {year} yet. Run `cargo time --year {year}` to record some."
+        );
+        process::exit(1);
+    };
+
+    let Some(baseline) = find_baseline(earlier, baseline) else {
+        match baseline {
+            Some(commit) => eprintln!("No benchmarks recorded for commit {commit}."),
+            None => {
+                eprintln!("Only one benchmark run recorded, run `cargo time --year {year}` again to compare.")
+            }
+        }
+        process::exit(1);
+    };
+
+    println!(
+        "{ANSI_BOLD}Comparing{ANSI_RESET} {} against {}\n",
+        latest.describe(),
+        baseline.describe()
+    );
+
+    let comparisons = compare(baseline, latest);
+    let width = comparisons.iter().map(|c| c.label.len()).max().unwrap_or(0);
+    let format_ns = |ns: Option<u64>| match ns {
+        Some(ns) => format!("{:.1?}", Duration::from_nanos(ns)),
+        None => "-".into(),
+    };
+
+    let mut regressions = 0;
+    for comparison in &comparisons {
+        let change = comparison
+            .change()
+            .map_or_else(String::new, |change| format!(" ({change:+.1}%)"));
+        let marker = if comparison.is_regression(threshold) {
+            regressions += 1;
+            " ✘"
+        } else {
+            ""
+        };
+
+        println!(
+            "{:width$}  {} -> {}{change}{marker}",
+            comparison.label,
+            format_ns(comparison.baseline_ns),
+            format_ns(comparison.latest_ns),
+        );
+    }
+
+    if regressions > 0 {
+        eprintln!("\n{regressions} timing(s) regressed by more than {threshold}%.");
+        process::exit(1);
+    }
+
+    println!("\nNo regressions above {threshold}%.");
+}
+
+/// The most recent run matching the commit prefix, or simply the most recent run.
+fn find_baseline<'a>(runs: &'a [Run], commit: Option<&str>) -> Option<&'a Run> {
+    match commit {
+        Some(commit) => runs.iter().rev().find(|run| {
+            run.commit
+                .as_deref()
+                .is_some_and(|c| c.starts_with(commit) || commit.starts_with(c))
+        }),
+        None => runs.last(),
+    }
+}
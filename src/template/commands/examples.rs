@@ -0,0 +1,170 @@
+use std::path::Path;
+use std::{fs, io, process};
+
+use crate::template::commands::scaffold::TESTS_TEMPLATE;
+use crate::template::puzzle::{self, Example};
+use crate::template::runner::print_read_error;
+use crate::template::{get_data_path, project_root};
+use crate::{Day, Year};
+
+pub fn handle(year: Year, day: Day) {
+    let markdown = puzzle::read(year, day).unwrap_or_else(|e| {
+        print_read_error(&e);
+        process::exit(1);
+    });
+
+    let examples = puzzle::extract_examples(&markdown);
+    if examples.is_empty() {
+        eprintln!(
+            "Found no examples with answers in \"{}\".",
+            puzzle::get_puzzle_path(year, day).display()
+        );
+        process::exit(1);
+    }
+
+    for (i, example) in examples.iter().enumerate() {
+        let path = get_data_path(year, "examples", &example_file_name(day, &examples, i));
+        match write_example(&path, &example.input) {
+            Ok(true) => println!("Wrote example to \"{}\".", path.display()),
+            Ok(false) => println!("Kept existing example \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Failed to write example: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    // the empty example created by `scaffold` would take precedence over the numbered ones.
+    if examples.len() > 1 {
+        let path = get_data_path(year, "examples", &format!("{day}.txt"));
+        if fs::read_to_string(&path).is_ok_and(|content| content.trim().is_empty()) {
+            let _ = fs::remove_file(&path);
+        }
+    }
+
+    let tests = generate_tests(&examples);
+    let module_path = project_root()
+        .join("src")
+        .join("bin")
+        .join(format!("{year}_{day}.rs"));
+
+    match fs::read_to_string(&module_path) {
+        Ok(module) if module.contains(TESTS_TEMPLATE) => {
+            if let Err(e) = fs::write(&module_path, module.replace(TESTS_TEMPLATE, &tests)) {
+                eprintln!("Failed to write tests: {e}");
+                process::exit(1);
+            }
+            println!("Filled in the tests of \"{}\".", module_path.display());
+        }
+        _ => {
+            println!("---");
+            println!("Tests for the examples:\n");
+            print!("{tests}");
+        }
+    }
+}
+
+/// `DD.txt` if the day has a single example, otherwise `DD-1.txt`, `DD-2.txt` etc.
+fn example_file_name(day: Day, examples: &[Example], index: usize) -> String {
+    if examples.len() == 1 {
+        format!("{day}.txt")
+    } else {
+        format!("{day}-{}.txt", index + 1)
+    }
+}
+
+/// Writes an example unless the file already has content, returning whether it was written.
+fn write_example(path: &Path, input: &str) -> io::Result<bool> {
+    if fs::read_to_string(path).is_ok_and(|content| !content.trim().is_empty()) {
+        return Ok(false);
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format!("{input}\n"))?;
+    Ok(true)
+}
+
+/// A test module with one test per part and example, in the format of [`TESTS_TEMPLATE`].
+fn generate_tests(examples: &[Example]) -> String {
+    let mut tests = String::from("#[cfg(test)]\nmod tests {\n    use super::*;\n");
+
+    for (part, name) in [(1, "part_one"), (2, "part_two")] {
+        let cases: Vec<(usize, &str)> = examples
+            .iter()
+            .enumerate()
+            .filter_map(|(i, example)| {
+                let (_, answer) = example.answers.iter().find(|(p, _)| *p == part)?;
+                Some((i, answer.as_str()))
+            })
+            .collect();
+
+        for (n, (i, answer)) in cases.iter().enumerate() {
+            let test_name = if cases.len() == 1 {
+                format!("test_{name}")
+            } else {
+                format!("test_{name}_{}", n + 1)
+            };
+
+            let read = if examples.len() == 1 {
+                format!(
+                    "let result = {name}(&advent_of_code::template::read_file(\"examples\", YEAR, DAY).unwrap());"
+                )
+            } else {
+                format!(
+                    "let result =\n            {name}(&advent_of_code::template::read_file_part(\"examples\", YEAR, DAY, {}).unwrap());",
+                    i + 1
+                )
+            };
+
+            let expected = if answer.parse::<i64>().is_ok() {
+                format!("Some({answer})")
+            } else {
+                format!("Some(String::from({answer:?}))")
+            };
+
+            tests.push_str(&format!(
+                "\n    #[test]\n    fn {test_name}() {{\n        {read}\n        assert_eq!(result, {expected});\n    }}\n"
+            ));
+        }
+    }
+
+    tests.push_str("}\n");
+    tests
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{generate_tests, TESTS_TEMPLATE};
+    use crate::template::puzzle::Example;
+
+    fn example(input: &str, answers: &[(u8, &str)]) -> Example {
+        Example {
+            input: input.into(),
+            answers: answers.iter().map(|(p, a)| (*p, a.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn generates_tests_in_template_format() {
+        let examples = [example("1abc2", &[(1, "0"), (2, "0")])];
+        let tests = generate_tests(&examples).replace("Some(0)", "None");
+        assert_eq!(tests, TESTS_TEMPLATE);
+    }
+
+    #[test]
+    fn generates_tests_of_numbered_examples() {
+        let examples = [
+            example("RL", &[(1, "2")]),
+            example("LLR", &[(1, "6")]),
+            example("LR", &[(2, "ZZZ")]),
+        ];
+        let tests = generate_tests(&examples);
+
+        assert!(tests.contains("fn test_part_one_1() {\n        let result =\n            part_one(&advent_of_code::template::read_file_part(\"examples\", YEAR, DAY, 1).unwrap());\n        assert_eq!(result, Some(2));"));
+        assert!(tests.contains("fn test_part_one_2() {"));
+        assert!(tests.contains("read_file_part(\"examples\", YEAR, DAY, 2)"));
+        assert!(tests.contains("fn test_part_two() {\n        let result =\n            part_two(&advent_of_code::template::read_file_part(\"examples\", YEAR, DAY, 3).unwrap());\n        assert_eq!(result, Some(String::from(\"ZZZ\")));"));
+    }
+}
@@ -1,5 +1,8 @@
 pub mod all;
+pub mod compare;
 pub mod download;
+pub mod examples;
 pub mod read;
+pub mod record;
 pub mod scaffold;
 pub mod solve;
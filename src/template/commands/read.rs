@@ -1,16 +1,15 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::puzzle;
+use crate::template::runner::print_read_error;
+use crate::{Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(year: Year, day: Day) {
+    match puzzle::read(year, day) {
+        Ok(markdown) => print!("{}", puzzle::render(&markdown)),
+        Err(e) => {
+            print_read_error(&e);
+            process::exit(1);
+        }
     }
-
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
 }
@@ -0,0 +1,50 @@
+use std::process;
+
+use crate::template::answers::{get_answers_path, Answers};
+use crate::template::commands::all::run_day;
+use crate::template::runner::RunOptions;
+use crate::template::Solution;
+use crate::{Day, Year};
+
+/// Runs a day's solution and records its answers as the expected ones for `verify`.
+pub fn handle(solutions: &[Solution], year: Year, day: Day) {
+    let Some(reports) = run_day(solutions, year, day, &RunOptions::default()) else {
+        eprintln!(
+            "Could not run day {day} of {year}. Has it been scaffolded and does it have an input?"
+        );
+        process::exit(1);
+    };
+
+    let mut answers = match Answers::read(year, day) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read recorded answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut recorded = 0;
+    for report in &reports {
+        if let (Some(part), Some(answer)) = (report.part, &report.answer) {
+            answers.set(part, answer);
+            println!("Part {part}: {answer}");
+            recorded += 1;
+        }
+    }
+
+    if recorded == 0 {
+        println!("No answers to record.");
+        return;
+    }
+
+    match answers.write(year, day) {
+        Ok(()) => println!(
+            "🎄 Recorded answers in \"{}\".",
+            get_answers_path(year, day).display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to write answers: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,14 +1,34 @@
-use std::process::{Command, Stdio};
-
-use crate::Day;
-
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+use std::process::{self, Command, Stdio};
+
+use crate::template::input::InputSource;
+use crate::template::runner::RunOptions;
+use crate::{Day, Year};
+
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    options: RunOptions,
+    submit_part: Option<u8>,
+    verify: bool,
+    input: &InputSource,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{year}_{day}"),
+    ];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    // the solution has to be built with the same allocator as this binary to report its allocations.
+    if cfg!(feature = "count_allocations") {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("count_allocations".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -16,8 +36,11 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
-        cmd_args.push("--time".to_string());
+    cmd_args.extend(options.to_args());
+    cmd_args.extend(input.to_args());
+
+    if verify {
+        cmd_args.push("--verify".to_string());
     }
 
     let mut cmd = Command::new("cargo")
@@ -27,5 +50,9 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    // forward a failing verification to the caller.
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
@@ -0,0 +1,194 @@
+/// Module that selects the input a solution binary runs on, see `--input` and `--example`.
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use crate::template::{get_data_path, read_file, read_file_part, ReadFileError};
+use crate::{Day, Year};
+
+/// Where a solution reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/<year>/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/<year>/examples`, or one of several numbered examples.
+    Example(Option<u8>),
+    /// A file, or every file in a directory.
+    Path(PathBuf),
+    /// The standard input, selected with `--input -`.
+    Stdin,
+}
+
+/// An input together with a name to tell it apart from others.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub content: String,
+}
+
+impl InputSource {
+    /// Selects the source from the value of `--input`, where `-` stands for the standard input.
+    #[must_use]
+    pub fn from_path(path: &str) -> Self {
+        if path == "-" {
+            Self::Stdin
+        } else {
+            Self::Path(PathBuf::from(path))
+        }
+    }
+
+    /// Reads the source of a solution binary from `--input <path>` or `--example [N]`.
+    /// As answers for other inputs are meaningless, these cannot be combined with `--submit` and `--verify`.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let value_of = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)?;
+            Some(args.get(index + 1).filter(|x| !x.starts_with("--")))
+        };
+
+        let source = match (value_of("--input"), value_of("--example")) {
+            (None, None) => Self::Puzzle,
+            (Some(Some(path)), None) => Self::from_path(path),
+            (None, Some(None)) => Self::Example(None),
+            (None, Some(Some(n))) => match n.parse::<u8>() {
+                Ok(n) => Self::Example(Some(n)),
+                Err(_) => exit_with_usage(),
+            },
+            _ => exit_with_usage(),
+        };
+
+        if source != Self::Puzzle && args.iter().any(|x| x == "--submit" || x == "--verify") {
+            eprintln!("--submit and --verify can only be used with the puzzle input.");
+            process::exit(1);
+        }
+
+        source
+    }
+
+    /// Turns the source back into the arguments [`InputSource::from_args`] reads.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+            Self::Path(path) => vec!["--input".into(), path.display().to_string()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Reads every input of the source, which are several only for a directory.
+    pub fn load(&self, year: Year, day: Day) -> Result<Vec<NamedInput>, ReadFileError> {
+        let named = |name: String, content: String| vec![NamedInput { name, content }];
+
+        match self {
+            Self::Puzzle => Ok(named(format!("{day}.txt"), read_file("inputs", year, day)?)),
+            Self::Example(None) => Ok(named(format!("{day}.txt"), read_example(year, day)?)),
+            Self::Example(Some(n)) => Ok(named(
+                format!("{day}-{n}.txt"),
+                read_file_part("examples", year, day, *n)?,
+            )),
+            Self::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|e| ReadFileError::new(Path::new("-"), e))?;
+                Ok(named("-".into(), content))
+            }
+            Self::Path(path) if path.is_dir() => {
+                read_dir(path).map_err(|e| ReadFileError::new(path, e))
+            }
+            Self::Path(path) => {
+                let content = fs::read_to_string(path).map_err(|e| ReadFileError::new(path, e))?;
+                Ok(named(file_name(path), content))
+            }
+        }
+    }
+}
+
+/// The example of a day, falling back to the first numbered example if the parts have different ones.
+fn read_example(year: Year, day: Day) -> Result<String, ReadFileError> {
+    if get_data_path(year, "examples", &format!("{day}.txt")).exists() {
+        read_file("examples", year, day)
+    } else {
+        read_file_part("examples", year, day, 1).or_else(|_| read_file("examples", year, day))
+    }
+}
+
+/// Reads every file in `dir` ordered by name, skipping hidden files such as `.keep`.
+fn read_dir(dir: &Path) -> io::Result<Vec<NamedInput>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<_>>()?;
+    paths.retain(|path| path.is_file() && !file_name(path).starts_with('.'));
+    paths.sort();
+
+    if paths.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "the directory contains no input files",
+        ));
+    }
+
+    paths
+        .iter()
+        .map(|path| {
+            Ok(NamedInput {
+                name: file_name(path),
+                content: fs::read_to_string(path)?,
+            })
+        })
+        .collect()
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into(),
+    )
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("Unexpected command-line input. Format: --input <path> or --example [number]");
+    process::exit(1);
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{read_dir, InputSource};
+
+    #[test]
+    fn converts_to_args() {
+        assert_eq!(InputSource::from_path("-"), InputSource::Stdin);
+        assert!(InputSource::Puzzle.to_args().is_empty());
+        assert_eq!(InputSource::Example(None).to_args(), ["--example"]);
+        assert_eq!(InputSource::Example(Some(2)).to_args(), ["--example", "2"]);
+        assert_eq!(
+            InputSource::from_path("inputs/alice.txt").to_args(),
+            ["--input", "inputs/alice.txt"]
+        );
+        assert_eq!(InputSource::Stdin.to_args(), ["--input", "-"]);
+    }
+
+    #[test]
+    fn reads_directories() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("bob.txt"), "2").unwrap();
+        fs::write(dir.join("alice.txt"), "1").unwrap();
+        fs::write(dir.join(".keep"), "").unwrap();
+
+        let inputs = read_dir(&dir).unwrap();
+        let names: Vec<_> = inputs.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["alice.txt", "bob.txt"]);
+        assert_eq!(inputs[1].content, "2");
+
+        fs::remove_file(dir.join("alice.txt")).unwrap();
+        fs::remove_file(dir.join("bob.txt")).unwrap();
+        assert!(read_dir(&dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,43 +1,239 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Day, Year};
+use runner::{Report, RunOptions};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::{env, fs, io};
 
-pub mod aoc_cli;
+pub mod allocations;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod input;
+pub mod puzzle;
 pub mod readme_benchmarks;
 pub mod runner;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// A file that could not be read, with the path it was looked up at.
+#[derive(Debug)]
+pub struct ReadFileError {
+    pub path: PathBuf,
+    pub source: io::Error,
+    /// A suggestion how to fix a missing file.
+    pub hint: Option<String>,
+}
+
+impl ReadFileError {
+    #[must_use]
+    pub fn new(path: &Path, source: io::Error) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            source,
+            hint: None,
+        }
+    }
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not read \"{}\": {}", self.path.display(), self.source)
+    }
+}
+
+impl std::error::Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The directory holding `Cargo.toml`, so that paths resolve the same from any subdirectory.
+///
+/// This is `CARGO_MANIFEST_DIR` when run through cargo, otherwise the closest ancestor of
+/// the working directory that contains a `Cargo.toml`, falling back to the working directory itself.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn project_root() -> PathBuf {
+    if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    let cwd = env::current_dir().unwrap_or_default();
+    cwd.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map_or_else(|| cwd.clone(), Path::to_path_buf)
+}
+
+/// The year set as `AOC_YEAR` in `.cargo/config.toml`, which commands use unless given `--year`.
+#[must_use]
+pub fn get_year() -> Option<Year> {
+    env::var("AOC_YEAR").ok()?.parse().ok()
+}
+
+/// The path of a file in `data/<year>/<folder>`.
+#[must_use]
+pub fn get_data_path(year: Year, folder: &str, file_name: &str) -> PathBuf {
+    project_root()
+        .join("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(file_name)
+}
+
+/// Helper function that reads a text file to a string.
+pub fn read_file(folder: &str, year: Year, day: Day) -> Result<String, ReadFileError> {
+    read_data_file(folder, &format!("{day}.txt"), year, day)
 }
 
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Reads one of several numbered files of a day, e.g. the example of a part if the parts have different ones.
+pub fn read_file_part(
+    folder: &str,
+    year: Year,
+    day: Day,
+    part: u8,
+) -> Result<String, ReadFileError> {
+    read_data_file(folder, &format!("{day}-{part}.txt"), year, day)
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+fn read_data_file(
+    folder: &str,
+    file_name: &str,
+    year: Year,
+    day: Day,
+) -> Result<String, ReadFileError> {
+    let path = get_data_path(year, folder, file_name);
+    fs::read_to_string(&path).map_err(|source| {
+        let hint = match folder {
+            _ if source.kind() != io::ErrorKind::NotFound => None,
+            "inputs" => Some(format!(
+                "run `cargo download {} --year {year}` to download your puzzle input.",
+                day.into_inner()
+            )),
+            "puzzles" => Some(format!(
+                "run `cargo download {} --year {year}` to download the puzzle description.",
+                day.into_inner()
+            )),
+            "examples" => Some(format!(
+                "copy the example from the puzzle description into \"{}\".",
+                path.display()
+            )),
+            _ => None,
+        };
+        ReadFileError { path, source, hint }
+    })
+}
+
+/// A solution that can be run in-process, see [`solution!`](crate::solution).
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// Runs the parse step, if any, and both parts on an input without printing anything.
+    /// The input is shared as parts that time out keep running on their own thread.
+    pub run: fn(Arc<str>, &RunOptions) -> Vec<Report>,
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part,
+/// e.g. `solution!(2023, 5)`. Naming a day the year's event does not have fails to compile.
+///
+/// Days that pass a parser, e.g. `solution!(2023, 5, parse)`, have their input parsed once
+/// and timed on its own, with `part_one` and `part_two` receiving a reference to the parsed input.
+///
+/// Days that are known to be slow can set their own timeout in seconds, which takes precedence over `--timeout`,
+/// e.g. `solution!(2023, 14, timeout = 30)` or `solution!(2023, 5, parse, timeout = 60)`.
+///
+/// Also creates the constant `SOLUTION`, which the main binary collects to run every day in a single process.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@common $year:expr, $day:expr, $timeout:expr) => {
+        /// The year of the puzzle.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
+
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        const _ASSERT_DAY_IN_YEAR: () = assert!(
+            YEAR.has_day(DAY),
+            concat!("the event of ", $year, " has no day ", $day),
+        );
+
+        /// The timeout this day sets for each of its parts.
+        const TIMEOUT: Option<std::time::Duration> = $timeout;
+    };
+    (@plain $year:expr, $day:expr, $timeout:expr) => {
+        advent_of_code::solution!(@common $year, $day, $timeout);
+
+        /// This day's entry in the registry of solutions run by the `all` command.
+        #[allow(dead_code)]
+        pub const SOLUTION: advent_of_code::template::Solution = advent_of_code::template::Solution {
+            year: YEAR,
+            day: DAY,
+            run: |input, options| {
+                use advent_of_code::template::runner::*;
+                let options = options.with_day_timeout(TIMEOUT);
+                vec![
+                    report_part(part_one, &input, YEAR, DAY, 1, &options),
+                    report_part(part_two, &input, YEAR, DAY, 2, &options),
+                ]
+            },
+        };
+
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let options = RunOptions::from_args().with_day_timeout(TIMEOUT);
+            run_inputs(&SOLUTION, &options, |input| {
+                run_part(part_one, &input, YEAR, DAY, 1, &options);
+                run_part(part_two, &input, YEAR, DAY, 2, &options);
+            });
+            exit_if_regressed();
         }
     };
+    (@parsed $year:expr, $day:expr, $parse:expr, $timeout:expr) => {
+        advent_of_code::solution!(@common $year, $day, $timeout);
+
+        /// This day's entry in the registry of solutions run by the `all` command.
+        #[allow(dead_code)]
+        pub const SOLUTION: advent_of_code::template::Solution = advent_of_code::template::Solution {
+            year: YEAR,
+            day: DAY,
+            run: |input, options| {
+                use advent_of_code::template::runner::*;
+                let options = options.with_day_timeout(TIMEOUT);
+                let (input, parse) = report_parse($parse, &input, YEAR, DAY, &options);
+                let input = std::sync::Arc::new(input);
+                vec![
+                    parse,
+                    report_part(part_one, &input, YEAR, DAY, 1, &options),
+                    report_part(part_two, &input, YEAR, DAY, 2, &options),
+                ]
+            },
+        };
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let options = RunOptions::from_args().with_day_timeout(TIMEOUT);
+            run_inputs(&SOLUTION, &options, |input| {
+                let input = std::sync::Arc::new(run_parse($parse, &input, YEAR, DAY, &options));
+                run_part(part_one, &input, YEAR, DAY, 1, &options);
+                run_part(part_two, &input, YEAR, DAY, 2, &options);
+            });
+            exit_if_regressed();
+        }
+    };
+    ($year:expr, $day:expr, timeout = $secs:expr) => {
+        advent_of_code::solution!(@plain $year, $day, Some(std::time::Duration::from_secs($secs)));
+    };
+    ($year:expr, $day:expr, $parse:expr, timeout = $secs:expr) => {
+        advent_of_code::solution!(@parsed $year, $day, $parse, Some(std::time::Duration::from_secs($secs)));
+    };
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(@plain $year, $day, None);
+    };
+    ($year:expr, $day:expr, $parse:expr) => {
+        advent_of_code::solution!(@parsed $year, $day, $parse, None);
+    };
 }
@@ -0,0 +1,656 @@
+/// Module that converts puzzle pages from HTML to the Markdown kept in `data/<year>/puzzles/DD.md`
+/// and renders that Markdown in the terminal, so puzzles can be read without network access.
+use std::path::PathBuf;
+
+use crate::template::{get_data_path, read_data_file, ReadFileError};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::{Day, Year};
+
+#[must_use]
+pub fn get_puzzle_path(year: Year, day: Day) -> PathBuf {
+    get_data_path(year, "puzzles", &format!("{day}.md"))
+}
+
+/// Reads the Markdown of a downloaded puzzle.
+pub fn read(year: Year, day: Day) -> Result<String, ReadFileError> {
+    read_data_file("puzzles", &format!("{day}.md"), year, day)
+}
+
+/// Converts the `<main>` element of a page, or the whole page if it has none, to Markdown.
+///
+/// Only the elements the puzzle pages use are converted: headings, paragraphs, lists, links,
+/// `<em>` as `*emphasis*`, `<code>` as inline code and `<pre>` as fenced code blocks.
+/// Forms and scripts are dropped, any other tag is removed with its text kept.
+#[must_use]
+pub fn html_to_markdown(html: &str) -> String {
+    let html = match (html.find("<main"), html.rfind("</main>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut converter = Converter::default();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        converter.text(&rest[..start]);
+        let end = rest[start..]
+            .find('>')
+            .map_or(rest.len(), |i| start + i + 1);
+        converter.tag(&rest[start..end]);
+        rest = &rest[end..];
+    }
+    converter.text(rest);
+
+    converter.finish()
+}
+
+#[derive(Default)]
+struct Converter {
+    out: String,
+    in_pre: bool,
+    /// The nesting depth of elements whose content is dropped.
+    skipped: usize,
+    heading_start: usize,
+    links: Vec<String>,
+}
+
+impl Converter {
+    fn tag(&mut self, tag: &str) {
+        let inner = tag
+            .trim_start_matches('<')
+            .trim_end_matches('>')
+            .trim_end_matches('/');
+        let is_closing = inner.starts_with('/');
+        let inner = inner.trim_start_matches('/');
+        let name = inner
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if matches!(name.as_str(), "form" | "script" | "style") {
+            if is_closing {
+                self.skipped = self.skipped.saturating_sub(1);
+            } else {
+                self.skipped += 1;
+            }
+            return;
+        }
+
+        if self.skipped > 0 {
+            return;
+        }
+
+        match (name.as_str(), is_closing) {
+            ("h1" | "h2" | "h3", false) => {
+                self.start_block();
+                self.heading_start = self.out.len();
+            }
+            ("h1" | "h2" | "h3", true) => {
+                // a heading such as `--- Day 1 ---` would otherwise read as a rule.
+                if self.out[self.heading_start..].starts_with('-') {
+                    self.out.insert(self.heading_start, '\\');
+                }
+                self.out.push_str("\n----------");
+                self.end_block();
+            }
+            ("p" | "ul", false) => self.start_block(),
+            ("p" | "ul", true) => self.end_block(),
+            ("li", false) => {
+                self.trim_end();
+                if !self.out.is_empty() && !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("* ");
+            }
+            ("li", true) => {
+                self.trim_end();
+                self.out.push('\n');
+            }
+            ("pre", false) => {
+                self.start_block();
+                self.out.push_str("```\n");
+                self.in_pre = true;
+            }
+            ("pre", true) => {
+                self.out.push_str("\n```");
+                self.in_pre = false;
+                self.end_block();
+            }
+            ("code", _) if !self.in_pre => self.out.push('`'),
+            ("em", _) if !self.in_pre => self.out.push('*'),
+            ("a", false) => {
+                self.out.push('[');
+                self.links
+                    .push(attribute(inner, "href").unwrap_or_default());
+            }
+            ("a", true) => {
+                let href = self.links.pop().unwrap_or_default();
+                self.out.push_str(&format!("]({href})"));
+            }
+            ("br", _) => self.out.push('\n'),
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skipped > 0 || text.is_empty() {
+            return;
+        }
+
+        let text = decode_entities(text);
+        if self.in_pre {
+            self.out.push_str(&text);
+            return;
+        }
+
+        for (i, word) in text.split_whitespace().enumerate() {
+            let starts_with_space = i > 0 || text.starts_with(char::is_whitespace);
+            if starts_with_space && !self.out.is_empty() && !self.out.ends_with(['\n', ' ']) {
+                self.out.push(' ');
+            }
+            self.out.push_str(&word.replace('*', "\\*"));
+        }
+
+        if text.ends_with(char::is_whitespace)
+            && !self.out.is_empty()
+            && !self.out.ends_with(['\n', ' '])
+        {
+            self.out.push(' ');
+        }
+    }
+
+    fn start_block(&mut self) {
+        self.trim_end();
+        if !self.out.is_empty() {
+            while !self.out.ends_with("\n\n") {
+                self.out.push('\n');
+            }
+        }
+    }
+
+    fn end_block(&mut self) {
+        self.trim_end();
+        self.out.push_str("\n\n");
+    }
+
+    fn trim_end(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+    }
+
+    fn finish(self) -> String {
+        let mut markdown = String::with_capacity(self.out.len());
+        let mut in_code_block = false;
+
+        for line in self.out.trim().lines() {
+            if line.starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+
+            // collapse runs of blank lines left by removed elements, but keep examples as they are.
+            if in_code_block {
+                markdown.push_str(line);
+            } else if line.trim().is_empty() && markdown.ends_with("\n\n") {
+                continue;
+            } else {
+                markdown.push_str(line.trim_end());
+            }
+            markdown.push('\n');
+        }
+        markdown
+    }
+}
+
+/// The value of an attribute in the inside of a tag, e.g. `a href="/2023"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+/// Decodes the named entities used by puzzle pages and numeric character references.
+#[must_use]
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end + 1));
+        let c = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => name.strip_prefix('#')?.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+
+        match (c, entity) {
+            (Some(c), Some((_, len))) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders puzzle Markdown for the terminal: headings and `*emphasis*` in bold, inline code in italics,
+/// code blocks as they are and links as their text.
+#[must_use]
+pub fn render(markdown: &str) -> String {
+    let mut rendered = String::with_capacity(markdown.len());
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            rendered.push_str(line);
+        } else if !line.is_empty() && line.chars().all(|c| c == '-' || c == '=') {
+            continue;
+        } else if line.starts_with("\\---") || line.starts_with("---") {
+            let heading = line.trim_start_matches('\\');
+            rendered.push_str(&format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+        } else if let Some(item) = line.strip_prefix("* ") {
+            rendered.push_str("• ");
+            rendered.push_str(&render_inline(item));
+        } else {
+            rendered.push_str(&render_inline(line));
+        }
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+fn render_inline(line: &str) -> String {
+    let mut rendered = String::with_capacity(line.len());
+    let (mut is_bold, mut is_code) = (false, false);
+    let mut chars = line.char_indices().peekable();
+
+    let style = |is_bold: bool, is_code: bool| {
+        let mut style = ANSI_RESET.to_string();
+        if is_bold {
+            style.push_str(ANSI_BOLD);
+        }
+        if is_code {
+            style.push_str(ANSI_ITALIC);
+        }
+        style
+    };
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    rendered.push(escaped);
+                }
+            }
+            '`' => {
+                is_code = !is_code;
+                rendered.push_str(&style(is_bold, is_code));
+            }
+            '*' => {
+                is_bold = !is_bold;
+                rendered.push_str(&style(is_bold, is_code));
+            }
+            '[' if !is_code => match split_link(&line[i..]) {
+                Some((text, len)) => {
+                    rendered.push_str(&render_inline(text));
+                    if is_bold {
+                        rendered.push_str(&style(is_bold, is_code));
+                    }
+                    while chars.peek().is_some_and(|(j, _)| *j < i + len) {
+                        chars.next();
+                    }
+                }
+                None => rendered.push(c),
+            },
+            c => rendered.push(c),
+        }
+    }
+
+    if is_bold || is_code {
+        rendered.push_str(ANSI_RESET);
+    }
+
+    rendered
+}
+
+/// Splits a link `[text](url)` at the start of `s` into its text and its length.
+fn split_link(s: &str) -> Option<(&str, usize)> {
+    let mut depth = 0;
+    let close = s.char_indices().find_map(|(i, c)| {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            _ => {}
+        }
+        (depth == 0).then_some(i)
+    })?;
+
+    let url = s[close + 1..].strip_prefix('(')?;
+    let mut depth = 1;
+    let end = url.char_indices().find_map(|(i, c)| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        (depth == 0).then_some(i)
+    })?;
+
+    Some((&s[1..close], close + 2 + end + 1))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An example input from a puzzle description with the answers the description gives for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// The expected answer of each part that uses the example, as `(part, answer)`.
+    pub answers: Vec<(u8, String)>,
+}
+
+/// Finds the example inputs of a puzzle and their expected answers in its Markdown.
+///
+/// An example is a code block introduced by a paragraph such as `For example:` or `Here's a larger example:`,
+/// other code blocks illustrate the steps of a solution. The expected answer is the last emphasized code,
+/// e.g. `` `*142*` ``, up to the next example, falling back to one in the introducing paragraph.
+/// Part two uses the last example of part one until it introduces its own. Examples without answers are skipped.
+#[must_use]
+pub fn extract_examples(markdown: &str) -> Vec<Example> {
+    let mut extractor = Extractor {
+        part: 1,
+        ..Extractor::default()
+    };
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        match &mut block {
+            Some(lines) if line.starts_with("```") => {
+                let input = lines.join("\n");
+                extractor.block(input.trim_end_matches('\n'));
+                block = None;
+            }
+            Some(lines) => lines.push(line),
+            None if line.starts_with("```") => block = Some(vec![]),
+            None if is_part_two_heading(line) => {
+                extractor.finish_part();
+                extractor.part = 2;
+            }
+            None if !line.trim().is_empty() => extractor.paragraph(line),
+            None => {}
+        }
+    }
+
+    extractor.finish_part();
+    extractor
+        .examples
+        .into_iter()
+        .filter(|example| !example.answers.is_empty())
+        .collect()
+}
+
+/// Phrases of paragraphs that introduce an example input.
+const EXAMPLE_INTROS: [&str; 4] = [
+    "for example",
+    "an example",
+    "another example",
+    "larger example",
+];
+
+#[derive(Default)]
+struct Extractor {
+    examples: Vec<Example>,
+    part: u8,
+    /// The index of the example that answers currently refer to.
+    current: Option<usize>,
+    paragraph: String,
+    paragraph_answers: Vec<String>,
+    intro_answers: Vec<String>,
+    answers: Vec<String>,
+}
+
+impl Extractor {
+    fn paragraph(&mut self, line: &str) {
+        self.answers.append(&mut self.paragraph_answers);
+        self.paragraph = line.to_lowercase();
+        self.paragraph_answers = emphasized_code(line);
+    }
+
+    fn block(&mut self, input: &str) {
+        let is_example = EXAMPLE_INTROS
+            .iter()
+            .any(|intro| self.paragraph.contains(intro));
+
+        if !is_example {
+            self.answers.append(&mut self.paragraph_answers);
+            self.paragraph.clear();
+            return;
+        }
+
+        let intro_answers = std::mem::take(&mut self.paragraph_answers);
+        self.finish_example();
+        let index = match self.examples.iter().position(|e| e.input == input) {
+            Some(index) => index,
+            None => {
+                self.examples.push(Example {
+                    input: input.to_string(),
+                    answers: vec![],
+                });
+                self.examples.len() - 1
+            }
+        };
+
+        self.current = Some(index);
+        self.intro_answers = intro_answers;
+        self.paragraph.clear();
+    }
+
+    fn finish_example(&mut self) {
+        self.answers.append(&mut self.paragraph_answers);
+        let answer = self.answers.pop().or_else(|| self.intro_answers.pop());
+
+        if let (Some(index), Some(answer)) = (self.current, answer) {
+            let example = &mut self.examples[index];
+            example.answers.retain(|(part, _)| *part != self.part);
+            example.answers.push((self.part, answer));
+        }
+
+        self.answers.clear();
+        self.intro_answers.clear();
+    }
+
+    fn finish_part(&mut self) {
+        self.finish_example();
+        self.paragraph.clear();
+    }
+}
+
+fn is_part_two_heading(line: &str) -> bool {
+    line.trim_start_matches('\\')
+        .starts_with("--- Part Two ---")
+}
+
+/// The contents of every `` `*emphasized code*` `` in a line.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut found = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find("`*") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("*`") else {
+            break;
+        };
+
+        // code that is only an asterisk, e.g. `` `*` ``, starts no emphasis.
+        if after[..end].contains('`') {
+            rest = &rest[start + 1..];
+            continue;
+        }
+
+        found.push(after[..end].to_string());
+        rest = &after[end + 2..];
+    }
+
+    found
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, extract_examples, html_to_markdown, render, Example};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    const PAGE: &str = r#"<html><body><header>Advent of Code</header><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with <a href="/2023/about" target="_blank">global snow production</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+<em>pqr3stu8vwx</em>
+</code></pre>
+<ul>
+<li><code>|</code> is a <em>vertical pipe</em>.</li>
+<li><code>S</code> is the &quot;start&quot;.</li>
+</ul>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>55291</code>.</p><p>You have two gold stars: **</p>
+<form method="post" action="1/answer"><p>Answer: <input type="text" name="answer"/></p></form>
+</main></body></html>"#;
+
+    const MARKDOWN: &str = r#"\--- Day 1: Trebuchet?! ---
+----------
+
+Something is *wrong* with [global snow production](/2023/about).
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+
+```
+
+* `|` is a *vertical pipe*.
+* `S` is the "start".
+
+Adding these together produces `*142*`.
+
+Your puzzle answer was `55291`.
+
+You have two gold stars: \*\*
+"#;
+
+    #[test]
+    fn converts_html_to_markdown() {
+        assert_eq!(html_to_markdown(PAGE), MARKDOWN);
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("&lt;a&gt; &amp;&amp; &#39;b&#x27; &unknown; & c"),
+            "<a> && 'b' &unknown; & c"
+        );
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let rendered = render(MARKDOWN);
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            format!("{ANSI_BOLD}--- Day 1: Trebuchet?! ---{ANSI_RESET}")
+        );
+        assert_eq!(
+            lines[2],
+            format!("Something is {ANSI_RESET}{ANSI_BOLD}wrong{ANSI_RESET} with global snow production.")
+        );
+        assert_eq!(lines[6..8], ["1abc2", "pqr3stu8vwx"]);
+        assert_eq!(
+            lines[10],
+            format!("• {ANSI_RESET}{ANSI_ITALIC}|{ANSI_RESET} is a {ANSI_RESET}{ANSI_BOLD}vertical pipe{ANSI_RESET}.")
+        );
+        assert_eq!(
+            lines[13],
+            format!("Adding these together produces {ANSI_RESET}{ANSI_ITALIC}{ANSI_RESET}{ANSI_BOLD}{ANSI_ITALIC}142{ANSI_RESET}{ANSI_ITALIC}{ANSI_RESET}.")
+        );
+        assert_eq!(lines[17], "You have two gold stars: **");
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let markdown = r#"\--- Day 8: Haunted Wasteland ---
+----------
+
+This format defines each *node* of the network individually. For example:
+
+```
+RL
+
+AAA = (BBB, CCC)
+
+```
+
+Go right to `*CCC*`, then left to `*ZZZ*`, which takes `*2*` steps.
+
+A `*` is no answer. For example, here is a situation that takes `*6*` steps:
+
+```
+LLR
+
+```
+
+Visually, the steps look like this:
+
+```
+AAA -> BBB
+
+```
+
+\--- Part Two ---
+----------
+
+Using the first example again, it takes `*3*` steps.
+"#;
+
+        assert_eq!(
+            extract_examples(markdown),
+            [
+                Example {
+                    input: "RL\n\nAAA = (BBB, CCC)".into(),
+                    answers: vec![(1, "2".into())],
+                },
+                Example {
+                    input: "LLR".into(),
+                    answers: vec![(1, "6".into()), (2, "3".into())],
+                },
+            ]
+        );
+    }
+}
@@ -1,10 +1,22 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::fmt::Display;
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::allocations::Allocations;
+use crate::template::project_root;
+use crate::{Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// The placeholder for the first table, and the markers of the single table written before tables were split by year.
+static LEGACY_MARKER: &str = "<!--- benchmarking table --->";
+
+/// Every table starts and ends with a marker comment.
+static MARKER_PREFIX: &str = "<!--- benchmarking table ";
+
+/// The markers around the table of `year`.
+fn marker(year: Year) -> String {
+    format!("{MARKER_PREFIX}{year} --->")
+}
 
 #[derive(Debug)]
 pub enum Error {
@@ -12,6 +24,15 @@ pub enum Error {
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(message) => write!(f, "{message}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -20,10 +41,14 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
+    pub year: Year,
     pub day: Day,
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// The allocations of the parse step and both parts, if they were counted.
+    pub allocations: Option<Allocations>,
 }
 
 pub struct TablePosition {
@@ -32,155 +57,248 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}_{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Finds the table between two occurrences of `marker`, or a single `marker` as a placeholder.
+/// Returns [`None`] if `readme` does not contain `marker`.
+fn locate_table(readme: &str, marker: &str) -> Result<Option<TablePosition>, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
-    let pos_start = matches
+    Ok(matches
         .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
-
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+        .zip(matches.last())
+        .map(|(first, last)| TablePosition {
+            pos_start: first.0,
+            pos_end: last.0 + last.1.len(),
+        }))
+}
 
-    Ok(TablePosition { pos_start, pos_end })
+/// Where to add the table of a year that has none yet: below the last table of another year,
+/// or at the end of the README if there is none.
+fn insert_position(readme: &str) -> usize {
+    readme
+        .rfind(MARKER_PREFIX)
+        .and_then(|start| readme[start..].find("--->").map(|end| start + end + 4))
+        .unwrap_or(readme.len())
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(prefix: &str, year: Year, timings: Vec<Timings>, total_millis: f64) -> String {
+    let marker = marker(year);
+    let header = format!("{prefix} {year} Benchmarks");
+    // the column is only shown for runs that counted allocations.
+    let has_allocations = timings.iter().any(|t| t.allocations.is_some());
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+    if has_allocations {
+        lines.push("| Day | Parse | Part 1 | Part 2 | Allocations |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        let path = get_path_for_bin(timing.year, timing.day);
+        let mut line = format!(
+            "| [Day {}]({}) | {} | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
+            timing
+                .parse
+                .map_or_else(|| "-".into(), |parse| format!("`{parse}`")),
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+        if has_allocations {
+            let allocations = timing
+                .allocations
+                .map_or_else(|| "-".into(), |a| a.to_string());
+            line.push_str(&format!(" {allocations} |"));
+        }
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Replaces the table of `year`, leaving the tables of other years as they are.
+/// A year without a table takes the place of the placeholder or is added below the other tables.
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let table = construct_table("##", year, timings, total_millis);
+
+    match locate_table(s, &marker(year))? {
+        Some(positions) => s.replace_range(positions.pos_start..positions.pos_end, &table),
+        None => match locate_table(s, LEGACY_MARKER)? {
+            Some(positions) => s.replace_range(positions.pos_start..positions.pos_end, &table),
+            None => {
+                let position = insert_position(s);
+                s.insert_str(position, &format!("\n\n{table}"));
+            }
+        },
+    }
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+/// Writes the table of `year` to the README in the project root.
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+    let path = project_root().join("README.md");
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{marker, update_content, Timings, LEGACY_MARKER};
+    use crate::template::allocations::Allocations;
+    use crate::{day, year};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
+                year: year!(2023),
                 day: day!(1),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
+                allocations: None,
             },
             Timings {
+                year: year!(2023),
                 day: day!(2),
+                parse: Some("5ms".into()),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
+                allocations: None,
             },
             Timings {
+                year: year!(2023),
                 day: day!(4),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
+                allocations: None,
             },
         ]
     }
 
     #[test]
-    #[should_panic]
-    fn errors_if_marker_not_present() {
+    fn adds_missing_table() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert!(s.starts_with("# readme\n\n<!--- benchmarking table 2023 --->\n## 2023 Benchmarks"));
+        assert!(s.ends_with("**Total: 190.00ms**\n<!--- benchmarking table 2023 --->"));
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2023));
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let mut s = format!("foo\nbar\n{}\nbaz", LEGACY_MARKER);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert!(!s.contains(LEGACY_MARKER));
+        assert!(s.contains("## 2023 Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let marker = marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&marker).count(), 2);
+        assert_eq!(s.matches("## 2023 Benchmarks").count(), 1);
+    }
+
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let mut s = format!("foo\n{}\nbaz", LEGACY_MARKER);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        let with_2023 = s.clone();
+
+        let mut timings = get_mock_timings();
+        for timing in &mut timings {
+            timing.year = year!(2022);
+        }
+        update_content(&mut s, year!(2022), timings.clone(), 100.0).unwrap();
+        assert!(s.starts_with(with_2023.trim_end_matches("\nbaz")));
+        assert!(s.contains("## 2022 Benchmarks"));
+        assert!(s.contains("| [Day 1](./src/bin/2022_01.rs) |"));
+        assert!(s.ends_with("<!--- benchmarking table 2022 --->\nbaz"));
+
+        update_content(&mut s, year!(2022), timings, 50.0).unwrap();
+        assert!(s.starts_with(with_2023.trim_end_matches("\nbaz")));
+        assert!(s.contains("**Total: 50.00ms**"));
+        assert!(!s.contains("**Total: 100.00ms**"));
+        assert_eq!(s.matches("## 2022 Benchmarks").count(), 1);
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## 2023 Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023_01.rs) | - | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | - | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_allocations() {
+        let mut timings = get_mock_timings();
+        timings[1].allocations = Some(Allocations {
+            count: 12,
+            bytes: 2048,
+        });
+
+        let mut s = LEGACY_MARKER.to_string();
+        update_content(&mut s, year!(2023), timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 | Allocations |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023_01.rs) | - | `10ms` | `20ms` | - |"));
+        assert!(s.contains(
+            "| [Day 2](./src/bin/2023_02.rs) | `5ms` | `30ms` | `40ms` | 12 allocations, 2.0 KiB |"
+        ));
+    }
 }
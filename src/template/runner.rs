@@ -1,67 +1,617 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::allocations::{format_bytes, Allocations};
+use crate::template::answers::{self, get_answers_path, Answers, Verdict};
+use crate::template::aoc_client::{self, AocClient};
+use crate::template::input::InputSource;
+use crate::template::submissions::{self, Attempt, SubmissionOutcome};
+use crate::template::{ReadFileError, Solution, ANSI_ITALIC, ANSI_RESET};
+use crate::{Day, Year};
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
+
+use serde::{Deserialize, Serialize};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+pub fn run_part<I, T>(
+    func: impl Fn(&I) -> Option<T> + Send + 'static,
+    input: &Arc<I>,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) where
+    I: ?Sized + Send + Sync + 'static,
+    T: Display + Send + 'static,
+{
     let part_str = format!("Part {part}");
+    let is_json = is_json_output();
+
+    let hook = {
+        let (part_str, config) = (part_str.clone(), options.bench);
+        move |result: &Option<T>| {
+            if !is_json {
+                print_result(result, &part_str, "");
+                print_benching(config.as_ref());
+            }
+        }
+    };
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let report = match run_limited(func, Arc::clone(input), options, hook) {
+        Some(measured) => Report {
+            year,
+            day,
+            part: Some(part),
+            answer: measured.result.as_ref().map(ToString::to_string),
+            stats: measured.stats,
+            timed_out: false,
+            peak_memory: measured.peak_memory,
+            allocations: measured.allocations,
+        },
+        None => Report::timed_out(year, day, part, options),
+    };
+    let verdict = is_verify().then(|| verify_report(&report));
+
+    if is_json {
+        print_json(&report);
+    } else {
+        print_report(&report, verdict.as_ref());
+    }
+
+    if let Some(answer) = report.answer {
+        submit_result(answer, year, day, part);
+    }
+}
+
+/// Runs a solution on the input selected with `--input` or `--example`, see [`InputSource`].
+/// A single input is passed to `run_single`, several inputs from a directory are run through
+/// the solution's [`Solution::run`] and printed as one row per file.
+pub fn run_inputs(solution: &Solution, options: &RunOptions, run_single: impl FnOnce(Arc<str>)) {
+    let source = InputSource::from_args();
+    let mut inputs = source
+        .load(solution.year, solution.day)
+        .unwrap_or_else(|e| {
+            print_read_error(&e);
+            process::exit(1);
+        });
+
+    if inputs.len() == 1 {
+        run_single(Arc::from(inputs.remove(0).content));
+        return;
+    }
+
+    let is_json = is_json_output();
+    let width = inputs
+        .iter()
+        .map(|input| input.name.len())
+        .max()
+        .unwrap_or(0);
+
+    for input in inputs {
+        let reports = (solution.run)(Arc::from(input.content), options);
+        if is_json {
+            for report in &reports {
+                print_json(&InputReport {
+                    input: &input.name,
+                    report,
+                });
+            }
+        } else {
+            println!("{}", format_row(&input.name, width, &reports));
+        }
+    }
+}
+
+/// Prints why a data file could not be read and, if possible, how to fix that.
+pub fn print_read_error(error: &ReadFileError) {
+    eprintln!("Error: {error}");
+    if let Some(hint) = &error.hint {
+        eprintln!("{ANSI_BOLD}Hint:{ANSI_RESET} {hint}");
+    }
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// A [`Report`] of one of several inputs, printed with `--json`.
+#[derive(Serialize)]
+struct InputReport<'a> {
+    input: &'a str,
+    #[serde(flatten)]
+    report: &'a Report,
+}
+
+/// Formats the reports of one input on a single line, with the input's name padded to `width`.
+fn format_row(name: &str, width: usize, reports: &[Report]) -> String {
+    let mut row = format!("{ANSI_BOLD}{name:width$}{ANSI_RESET}");
+
+    for report in reports {
+        let cell = match report.part {
+            None => format!("Parse ({:.1?})", report.stats.median),
+            Some(part) if report.timed_out => format!("Part {part}: ⏱ timed out"),
+            Some(part) => match &report.answer {
+                Some(answer) => format!("Part {part}: {answer} ({:.1?})", report.stats.median),
+                None => format!("Part {part}: ✖"),
+            },
+        };
+        row.push_str(" │ ");
+        row.push_str(&cell);
+    }
+
+    row
+}
+
+/// Set once a part's answer differs from the recorded one while verifying.
+static REGRESSED: AtomicBool = AtomicBool::new(false);
+
+/// With `--verify`, solution binaries check every answer against the one recorded in `data/<year>/answers`.
+fn is_verify() -> bool {
+    env::args().any(|x| x == "--verify")
+}
+
+/// Checks the answer of a part against the recorded answers of its day.
+/// Parse steps and days without readable answers are [`Verdict::Unrecorded`].
+pub fn verify_report(report: &Report) -> Verdict {
+    let Some(part) = report.part else {
+        return Verdict::Unrecorded;
+    };
+
+    let verdict = match Answers::read(report.year, report.day) {
+        Ok(answers) => answers.verify(part, report.answer.as_deref()),
+        Err(e) => {
+            eprintln!("Failed to read recorded answers: {e}");
+            Verdict::Unrecorded
+        }
+    };
+
+    if matches!(verdict, Verdict::Incorrect { .. }) {
+        REGRESSED.store(true, Ordering::Relaxed);
+    }
+
+    verdict
+}
+
+/// Exits with a non-zero status if verifying found an answer that differs from the recorded one.
+pub fn exit_if_regressed() {
+    if REGRESSED.load(Ordering::Relaxed) {
+        process::exit(1);
+    }
+}
+
+/// Run the parser of a day that separates parsing from solving, timing it like a solution part.
+/// Parsing is not subject to the timeout.
+pub fn run_parse<'a, T>(
+    func: impl Fn(&'a str) -> T,
+    input: &'a str,
+    year: Year,
+    day: Day,
+    options: &RunOptions,
+) -> T {
+    let is_json = is_json_output();
+    let config = options.bench;
+
+    let measured = run_timed(func, input, options, |_| {
+        if !is_json {
+            print!("Parse:");
+            print_benching(config.as_ref());
+        }
+    });
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let report = Report::parse(year, day, &measured);
+    if is_json {
+        print_json(&report);
+    } else {
+        print!("\r");
+        print_report(&report, None);
+    }
+
+    measured.result
+}
+
+/// Run a solution part without printing anything, benching it if the options say so.
+pub fn report_part<I, T>(
+    func: impl Fn(&I) -> Option<T> + Send + 'static,
+    input: &Arc<I>,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> Report
+where
+    I: ?Sized + Send + Sync + 'static,
+    T: Display + Send + 'static,
+{
+    match run_limited(func, Arc::clone(input), options, |_| {}) {
+        Some(measured) => Report {
+            year,
+            day,
+            part: Some(part),
+            answer: measured.result.map(|result| result.to_string()),
+            stats: measured.stats,
+            timed_out: false,
+            peak_memory: measured.peak_memory,
+            allocations: measured.allocations,
+        },
+        None => Report::timed_out(year, day, part, options),
+    }
+}
+
+/// Run the parser of a day without printing anything, benching it if the options say so.
+pub fn report_parse<'a, T>(
+    func: impl Fn(&'a str) -> T,
+    input: &'a str,
+    year: Year,
+    day: Day,
+    options: &RunOptions,
+) -> (T, Report) {
+    let measured = run_timed(func, input, options, |_| {});
+    let report = Report::parse(year, day, &measured);
+    (measured.result, report)
+}
+
+/// With `--json`, solution binaries print one [`Report`] per line instead of human-readable output.
+fn is_json_output() -> bool {
+    env::args().any(|x| x == "--json")
+}
+
+/// The outcome of running a parse step or a solution part, printed as a line of JSON with `--json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub year: Year,
+    pub day: Day,
+    /// The solution part, [`None`] for the parse step.
+    pub part: Option<u8>,
+    /// The answer, [`None`] for the parse step and unsolved parts.
+    pub answer: Option<String>,
+    #[serde(flatten)]
+    pub stats: BenchStats,
+    /// Whether the part was given up on after exceeding the timeout, its duration is the timeout then.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+    /// The peak resident memory of the process during the first run, if measured.
+    #[serde(
+        rename = "peak_memory_bytes",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub peak_memory: Option<u64>,
+    /// The heap allocations made during the first run, if the `count_allocations` feature is enabled.
+    #[serde(flatten)]
+    pub allocations: Option<Allocations>,
+}
+
+impl Report {
+    fn parse<T>(year: Year, day: Day, measured: &Measured<T>) -> Self {
+        Self {
+            year,
+            day,
+            part: None,
+            answer: None,
+            stats: measured.stats,
+            timed_out: false,
+            peak_memory: measured.peak_memory,
+            allocations: measured.allocations,
+        }
+    }
+
+    fn timed_out(year: Year, day: Day, part: u8, options: &RunOptions) -> Self {
+        Self {
+            year,
+            day,
+            part: Some(part),
+            answer: None,
+            stats: BenchStats::from_samples(&[options.timeout.unwrap_or_default()]),
+            timed_out: true,
+            peak_memory: None,
+            allocations: None,
+        }
+    }
+}
+
+fn print_json(report: &impl Serialize) {
+    println!("{}", serde_json::to_string(report).unwrap());
+}
+
+/// Prints a report in the same format a solution binary prints without `--json`.
+pub fn print_report(report: &Report, verdict: Option<&Verdict>) {
+    let suffix = format_report_suffix(report, verdict);
+    match report.part {
+        Some(part) if report.timed_out => {
+            print!("\r");
+            println!(
+                "Part {part}: ⏱ timed out after {:.1?}{}",
+                report.stats.median,
+                verdict.map_or_else(String::new, |verdict| format!(" {verdict}"))
+            );
+        }
+        Some(part) => print_result(&report.answer, &format!("Part {part}"), &suffix),
+        None => println!("Parse:{suffix}"),
+    }
+}
+
+fn format_report_suffix(report: &Report, verdict: Option<&Verdict>) -> String {
+    let mut suffix = format_duration(&report.stats);
+    if let Some(bytes) = report.peak_memory {
+        suffix.push_str(&format!(" [peak memory {}]", format_bytes(bytes)));
+    }
+    if let Some(allocations) = report.allocations {
+        suffix.push_str(&format!(" [{allocations}]"));
+    }
+    match verdict {
+        Some(verdict) if report.part.is_some() => format!("{suffix} {verdict}"),
+        _ => suffix,
+    }
+}
+
+/// How solution parts are run, set through `--time`, `--budget`, `--timeout` and `--memory`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Bench parts after their first run.
+    pub bench: Option<BenchConfig>,
+    /// Give up on a part if its first run takes longer.
+    pub timeout: Option<Duration>,
+    /// Measure the peak memory usage during the first run of each part.
+    pub measure_memory: bool,
+}
+
+impl RunOptions {
+    /// Creates the options from command-line values, `budget` is only used if `is_timed` is set.
+    /// Returns the expected format of an invalid value as the error.
+    pub fn new(
+        is_timed: bool,
+        budget: Option<f64>,
+        timeout: Option<f64>,
+        measure_memory: bool,
+    ) -> Result<Self, &'static str> {
+        let bench = match (is_timed, budget) {
+            (false, _) => None,
+            (true, None) => Some(BenchConfig::default()),
+            (true, Some(secs)) => Some(BenchConfig::with_budget(secs).ok_or("--budget <seconds>")?),
+        };
+
+        let timeout = match timeout {
+            Some(secs) if secs.is_finite() && secs > 0_f64 => Some(Duration::from_secs_f64(secs)),
+            Some(_) => return Err("--timeout <seconds>"),
+            None => None,
+        };
+
+        Ok(Self {
+            bench,
+            timeout,
+            measure_memory,
+        })
+    }
+
+    /// Reads the options of a solution binary from its command-line arguments.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let seconds = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)?;
+            let value = args.get(index + 1).and_then(|x| x.parse::<f64>().ok());
+            if value.is_none() {
+                eprintln!("Unexpected command-line input. Format: {flag} <seconds>");
+                process::exit(1);
+            }
+            value
+        };
+
+        let (budget, timeout) = (seconds("--budget"), seconds("--timeout"));
+        let is_timed = args.iter().any(|x| x == "--time");
+        let measure_memory = args.iter().any(|x| x == "--memory");
+
+        Self::new(is_timed, budget, timeout, measure_memory).unwrap_or_else(|format| {
+            eprintln!("Unexpected command-line input. Format: {format}");
+            process::exit(1);
+        })
+    }
+
+    /// Turns the options back into the arguments [`RunOptions::from_args`] reads.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(config) = self.bench {
+            args.push("--time".into());
+            args.push("--budget".into());
+            args.push(config.budget.as_secs_f64().to_string());
+        }
+        if let Some(timeout) = self.timeout {
+            args.push("--timeout".into());
+            args.push(timeout.as_secs_f64().to_string());
+        }
+        if self.measure_memory {
+            args.push("--memory".into());
+        }
+        args
+    }
+
+    /// Uses the timeout a day sets for itself in place of the one given on the command-line.
+    #[must_use]
+    pub fn with_day_timeout(self, timeout: Option<Duration>) -> Self {
+        Self {
+            timeout: timeout.or(self.timeout),
+            ..self
+        }
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// The result of a parse step or solution part together with its measurements.
+struct Measured<T> {
+    result: T,
+    stats: BenchStats,
+    peak_memory: Option<u64>,
+    allocations: Option<Allocations>,
+}
+
+/// Run a solution part once and, if the options say so, bench it afterwards.
+/// `hook` is called with the result of the first run before benching starts.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> Measured<T> {
+    let is_measuring_memory = options.measure_memory && peak_memory::reset();
+
+    let allocations_before = Allocations::current();
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
+    let allocations = allocations_before
+        .zip(Allocations::current())
+        .map(|(before, after)| after.since(&before));
+
+    let peak_memory = if is_measuring_memory {
+        peak_memory::read()
+    } else {
+        None
+    };
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let stats = match &options.bench {
+        Some(config) => bench(func, input, config),
+        None => BenchStats::from_samples(&[base_time]),
+    };
+
+    Measured {
+        result,
+        stats,
+        peak_memory,
+        allocations,
+    }
+}
+
+/// Runs a solution part through [`run_timed`] on its own thread if the options set a timeout,
+/// returning [`None`] if its first run takes longer than that.
+///
+/// A part that timed out cannot be stopped, its thread keeps running in the background until the process exits
+/// and holds on to its share of `input` until then.
+fn run_limited<I, T>(
+    func: impl Fn(&I) -> T + Send + 'static,
+    input: Arc<I>,
+    options: &RunOptions,
+    hook: impl Fn(&T) + Send + 'static,
+) -> Option<Measured<T>>
+where
+    I: ?Sized + Send + Sync + 'static,
+    T: Send + 'static,
+{
+    let Some(timeout) = options.timeout else {
+        return Some(run_timed(func, &*input, options, hook));
     };
 
-    (result, run.0, run.1)
+    let options = *options;
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        run_timed(func, &*input, &options, |result| {
+            // the receiver is gone once the part timed out, nothing should be printed for it then.
+            if sender.send(()).is_ok() {
+                hook(result);
+            }
+        })
+    });
+
+    match receiver.recv_timeout(timeout) {
+        // a disconnect without a message means the part panicked, which joining passes on.
+        Ok(()) | Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Ok(measured) => Some(measured),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+        Err(RecvTimeoutError::Timeout) => None,
+    }
+}
+
+fn print_benching(config: Option<&BenchConfig>) {
+    if config.is_some() {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+}
+
+/// Measures peak memory through the high water mark of the resident set size, which only Linux exposes.
+/// The mark covers the whole process, including memory held before the part started.
+mod peak_memory {
+    use std::fs;
+
+    /// Resets the high water mark to the current usage, returns `false` if that is not supported.
+    pub fn reset() -> bool {
+        fs::write("/proc/self/clear_refs", "5").is_ok()
+    }
+
+    /// The highest resident set size in bytes since the last reset.
+    pub fn read() -> Option<u64> {
+        parse_status(&fs::read_to_string("/proc/self/status").ok()?)
+    }
+
+    /// Reads the `VmHWM` line of `/proc/self/status`, which is given in kibibytes.
+    pub fn parse_status(status: &str) -> Option<u64> {
+        let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+        let kib = line
+            .trim_start_matches("VmHWM:")
+            .trim()
+            .trim_end_matches("kB")
+            .trim();
+        kib.parse::<u64>().ok().map(|kib| kib * 1024)
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Controls how long a solution part is benched for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent running the part untimed to warm caches and the branch predictor.
+    pub warm_up: Duration,
+    /// Time spent collecting samples once warmed up.
+    pub budget: Duration,
+    /// Samples taken even if they exceed the budget.
+    pub min_samples: usize,
+    /// Samples after which benching stops even if budget is left.
+    pub max_samples: usize,
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(100),
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+impl BenchConfig {
+    /// Creates the default config with a time budget of `secs` seconds instead of one second.
+    /// Returns [`None`] if `secs` is not a positive number.
+    pub fn with_budget(secs: f64) -> Option<Self> {
+        if !secs.is_finite() || secs <= 0_f64 {
+            return None;
+        }
+
+        let default = Self::default();
+        Some(Self {
+            warm_up: default.warm_up.min(Duration::from_secs_f64(secs / 10_f64)),
+            budget: Duration::from_secs_f64(secs),
+            ..default
+        })
+    }
+}
+
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, config: &BenchConfig) -> BenchStats {
+    let warm_up = Instant::now();
+    while warm_up.elapsed() < config.warm_up {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let start = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while timers.len() < config.min_samples
+        || (timers.len() < config.max_samples && start.elapsed() < config.budget)
+    {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -69,26 +619,121 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Summary statistics of the samples taken while benching a solution part.
+///
+/// Durations serialize as whole nanoseconds, the median as `duration_ns`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    /// Samples the statistics are computed from.
+    pub samples: usize,
+    /// Samples rejected as outliers before computing the statistics.
+    pub outliers: usize,
+    #[serde(rename = "duration_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+    #[serde(rename = "std_dev_ns", with = "nanos")]
+    pub std_dev: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "p99_ns", with = "nanos")]
+    pub p99: Duration,
+}
+
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+impl BenchStats {
+    /// Computes statistics after rejecting samples outside of Tukey's fences,
+    /// i.e. more than 1.5 interquartile ranges below the first or above the third quartile.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "cannot summarize zero samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25_f64).as_nanos() as f64;
+        let q3 = percentile(&sorted, 75_f64).as_nanos() as f64;
+        let fence = 1.5 * (q3 - q1);
+        let kept: Vec<Duration> = sorted
+            .iter()
+            .copied()
+            .filter(|x| {
+                let nanos = x.as_nanos() as f64;
+                nanos >= q1 - fence && nanos <= q3 + fence
+            })
+            .collect();
+
+        let mean = kept.iter().map(Duration::as_nanos).sum::<u128>() as f64 / kept.len() as f64;
+        let variance = kept
+            .iter()
+            .map(|x| (x.as_nanos() as f64 - mean).powi(2))
+            .sum::<f64>()
+            / kept.len() as f64;
+
+        Self {
+            samples: kept.len(),
+            outliers: sorted.len() - kept.len(),
+            mean: Duration::from_secs_f64(mean / 1e9),
+            median: percentile(&kept, 50_f64),
+            min: kept[0],
+            max: kept[kept.len() - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+            p95: percentile(&kept, 95_f64),
+            p99: percentile(&kept, 99_f64),
+        }
+    }
+}
+
+/// Nearest-rank percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p / 100_f64 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+/// Formats the median, followed by the spread of the samples if the part was benched.
+fn format_duration(stats: &BenchStats) -> String {
+    let BenchStats {
+        samples,
+        outliers,
+        median,
+        min,
+        max,
+        std_dev,
+        p95,
+        p99,
+        ..
+    } = stats;
+
+    if *samples + *outliers == 1 {
+        format!(" ({median:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({median:.1?} @ {samples} samples) [min {min:.1?}, p95 {p95:.1?}, p99 {p99:.1?}, max {max:.1?}, σ {std_dev:.1?}, {outliers} outliers]"
+        )
     }
 }
 
@@ -129,16 +774,13 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured, see [`AocClient::from_env`].
+///  3. the log of earlier submissions does not rule out the answer, see [`submissions::check`].
+fn submit_result<T: Display>(result: T, year: Year, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -154,14 +796,223 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let result = result.to_string();
+    let attempts = submissions::read(year, day).unwrap_or_else(|e| {
+        eprintln!("failed to read earlier submissions: {e}");
+        process::exit(1);
+    });
+
+    if let Err(refusal) = submissions::check(&attempts, part, &result, submissions::now()) {
+        eprintln!("Not submitting {result}: {refusal}");
+        process::exit(1);
+    }
+
+    let client = AocClient::from_env(year).unwrap_or_else(|e| {
+        eprintln!("failed to submit: {e}");
+        process::exit(1);
+    });
+
+    println!("Submitting result...");
+    let outcome = client.submit(day, part, &result).unwrap_or_else(|e| {
+        eprintln!("failed to submit: {e}");
         process::exit(1);
+    });
+    println!("{outcome}");
+
+    let is_correct = outcome == SubmissionOutcome::Correct;
+    if let Err(e) = submissions::append(year, day, &Attempt::new(part, &result, outcome)) {
+        eprintln!("Failed to log submission: {e}");
+    }
+
+    if is_correct {
+        match answers::record(year, day, part, &result) {
+            Ok(()) => println!(
+                "Recorded answer in {}.",
+                get_answers_path(year, day).display()
+            ),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
+    }
+
+    // part two is only on the puzzle page once part one is solved.
+    if is_correct && part == 1 {
+        if let Err(e) = aoc_client::download_puzzle(&client, day) {
+            eprintln!("Failed to update the puzzle with part two: {e}");
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::{
+        format_bytes, format_duration, format_row, peak_memory, run_limited, BenchStats, Report,
+        RunOptions,
+    };
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+    use crate::{day, year};
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_micros(*x)).collect()
+    }
+
+    #[test]
+    fn test_stats_of_single_run() {
+        let stats = BenchStats::from_samples(&micros(&[42]));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.median, Duration::from_micros(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(format_duration(&stats), " (42.0µs)");
+    }
+
+    #[test]
+    fn test_stats_reject_outliers() {
+        let mut samples = micros(&[10, 11, 12, 13, 14, 10, 11, 12, 13, 14]);
+        samples.push(Duration::from_millis(50));
+
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.max, Duration::from_micros(14));
+        assert_eq!(stats.median, Duration::from_micros(12));
+        assert_eq!(stats.mean, Duration::from_micros(12));
+        assert_eq!(stats.p95, Duration::from_micros(14));
+        assert_eq!(stats.std_dev.as_nanos(), 1414);
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let samples = micros(&(1..=100).collect::<Vec<_>>());
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.median, Duration::from_micros(50));
+        assert_eq!(stats.p95, Duration::from_micros(95));
+        assert_eq!(stats.p99, Duration::from_micros(99));
+        assert!(format_duration(&stats).starts_with(" (50.0µs @ 100 samples) [min 1.0µs"));
+    }
+
+    #[test]
+    fn test_run_options() {
+        let options = RunOptions::new(true, Some(0.5), Some(10_f64), true).unwrap();
+        assert_eq!(options.bench.unwrap().budget, Duration::from_millis(500));
+        assert_eq!(options.timeout, Some(Duration::from_secs(10)));
+        assert_eq!(
+            options.to_args(),
+            ["--time", "--budget", "0.5", "--timeout", "10", "--memory"]
+        );
+
+        assert_eq!(
+            RunOptions::new(false, Some(0.5), None, false).unwrap(),
+            RunOptions::default()
+        );
+        assert_eq!(
+            RunOptions::new(false, None, Some(0_f64), false),
+            Err("--timeout <seconds>")
+        );
+        assert_eq!(
+            RunOptions::new(true, Some(-1_f64), None, false),
+            Err("--budget <seconds>")
+        );
+
+        let day = options.with_day_timeout(Some(Duration::from_secs(60)));
+        assert_eq!(day.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(options.with_day_timeout(None), options);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..RunOptions::default()
+        };
+
+        let slow = run_limited(
+            |x: &u64| {
+                std::thread::sleep(Duration::from_secs(5));
+                *x
+            },
+            Arc::new(1),
+            &options,
+            |_| {},
+        );
+        assert!(slow.is_none());
+
+        let fast = run_limited(|x: &u64| x * 2, Arc::new(21), &options, |_| {}).unwrap();
+        assert_eq!(fast.result, 42);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn test_peak_memory() {
+        let status = "Name:\tadvent_of_code\nVmPeak:\t  10000 kB\nVmHWM:\t    1440 kB\nVmRSS:\t    1000 kB\n";
+        assert_eq!(peak_memory::parse_status(status), Some(1440 * 1024));
+        assert_eq!(peak_memory::parse_status("Name:\tx\n"), None);
+
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+    }
+
+    #[test]
+    fn test_rows() {
+        let report = |part: Option<u8>, answer: Option<&str>, micros: u64| Report {
+            year: year!(2023),
+            day: day!(5),
+            part,
+            answer: answer.map(Into::into),
+            stats: BenchStats::from_samples(&[Duration::from_micros(micros)]),
+            timed_out: false,
+            peak_memory: None,
+            allocations: None,
+        };
+
+        let mut timed_out = report(Some(2), None, 1000);
+        timed_out.timed_out = true;
+        let reports = [
+            report(None, None, 2),
+            report(Some(1), Some("35"), 1),
+            timed_out,
+        ];
+
+        assert_eq!(
+            format_row("bob.txt", 9, &reports),
+            format!(
+                "{ANSI_BOLD}bob.txt  {ANSI_RESET} │ Parse (2.0µs) │ Part 1: 35 (1.0µs) │ Part 2: ⏱ timed out"
+            )
+        );
+        assert_eq!(
+            format_row("a", 1, &[report(Some(1), None, 1)]),
+            format!("{ANSI_BOLD}a{ANSI_RESET} │ Part 1: ✖")
+        );
+    }
+
+    #[test]
+    fn test_report_roundtrip() {
+        let json = r#"{"year":2023,"day":5,"part":1,"answer":"35","samples":3,"outliers":0,"duration_ns":42,"mean_ns":42,"min_ns":40,"max_ns":44,"std_dev_ns":1,"p95_ns":44,"p99_ns":44}"#;
+        let report: Report = serde_json::from_str(json).unwrap();
+        assert_eq!(report.year, year!(2023));
+        assert_eq!(report.day, day!(5));
+        assert_eq!(report.part, Some(1));
+        assert_eq!(report.answer.as_deref(), Some("35"));
+        assert_eq!(report.stats.median, Duration::from_nanos(42));
+        assert!(!report.timed_out);
+        assert_eq!(report.peak_memory, None);
+        assert_eq!(report.allocations, None);
+        assert_eq!(serde_json::to_string(&report).unwrap(), json);
+
+        let json = r#"{"year":2023,"day":5,"part":2,"answer":null,"samples":1,"outliers":0,"duration_ns":42,"mean_ns":42,"min_ns":42,"max_ns":42,"std_dev_ns":0,"p95_ns":42,"p99_ns":42,"timed_out":true,"peak_memory_bytes":2048}"#;
+        let report: Report = serde_json::from_str(json).unwrap();
+        assert!(report.timed_out);
+        assert_eq!(report.peak_memory, Some(2048));
+        assert_eq!(serde_json::to_string(&report).unwrap(), json);
+
+        let json = r#"{"year":2023,"day":5,"part":1,"answer":"35","samples":1,"outliers":0,"duration_ns":42,"mean_ns":42,"min_ns":42,"max_ns":42,"std_dev_ns":0,"p95_ns":42,"p99_ns":42,"allocations":3,"allocated_bytes":96}"#;
+        let report: Report = serde_json::from_str(json).unwrap();
+        assert_eq!(report.allocations.unwrap().count, 3);
+        assert_eq!(serde_json::to_string(&report).unwrap(), json);
+    }
 }
@@ -0,0 +1,463 @@
+/// Module that logs every submitted answer in `data/<year>/submissions/DD.jsonl`, one JSON object per attempt,
+/// and uses the log to refuse submissions that are known to be wrong or would run into a cooldown.
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+use crate::{Day, Year};
+
+/// How many characters of a response that could not be recognised are kept.
+const MAX_MESSAGE_CHARS: usize = 300;
+
+/// What the Advent of Code website answered to a submission.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        wait_secs: Option<u64>,
+    },
+    TooLow {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        wait_secs: Option<u64>,
+    },
+    Incorrect {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        wait_secs: Option<u64>,
+    },
+    /// The previous answer was submitted too recently, the answer was not checked.
+    RateLimited {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        wait_secs: Option<u64>,
+    },
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// Any other response, keeping at most its first few hundred characters.
+    Unknown {
+        message: String,
+    },
+}
+
+impl SubmissionOutcome {
+    /// Parses the text of the response to a submission.
+    #[must_use]
+    pub fn parse(text: &str) -> Self {
+        let wait_secs = parse_wait(text);
+
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Self::TooHigh { wait_secs }
+            } else if text.contains("your answer is too low") {
+                Self::TooLow { wait_secs }
+            } else {
+                Self::Incorrect { wait_secs }
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Self::RateLimited { wait_secs }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            let message = text.trim();
+            Self::Unknown {
+                message: match message.char_indices().nth(MAX_MESSAGE_CHARS) {
+                    Some((end, _)) => format!("{}…", &message[..end]),
+                    None => message.to_string(),
+                },
+            }
+        }
+    }
+
+    /// Whether the website checked the answer and found it wrong.
+    #[must_use]
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Self::TooHigh { .. } | Self::TooLow { .. } | Self::Incorrect { .. }
+        )
+    }
+
+    /// How many seconds to wait before the next submission.
+    #[must_use]
+    pub fn wait_secs(&self) -> Option<u64> {
+        match self {
+            Self::TooHigh { wait_secs }
+            | Self::TooLow { wait_secs }
+            | Self::Incorrect { wait_secs }
+            | Self::RateLimited { wait_secs } => *wait_secs,
+            _ => None,
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!")?,
+            Self::TooHigh { .. } => write!(f, "That's not the right answer, it is too high.")?,
+            Self::TooLow { .. } => write!(f, "That's not the right answer, it is too low.")?,
+            Self::Incorrect { .. } => write!(f, "That's not the right answer.")?,
+            Self::RateLimited { .. } => write!(f, "You gave an answer too recently.")?,
+            Self::WrongLevel => write!(f, "This part is already solved or not unlocked yet.")?,
+            Self::Unknown { message } => write!(f, "{message}")?,
+        }
+
+        match self.wait_secs() {
+            Some(secs) => write!(f, " Wait {} before trying again.", format_wait(secs)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A submitted answer and the response to it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub outcome: SubmissionOutcome,
+}
+
+impl Attempt {
+    #[must_use]
+    pub fn new(part: u8, answer: &str, outcome: SubmissionOutcome) -> Self {
+        Self {
+            timestamp: now(),
+            part,
+            answer: answer.to_string(),
+            outcome,
+        }
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved {
+        answer: String,
+    },
+    KnownWrong,
+    /// The answer is not below an answer that was too high.
+    NotBelow {
+        bound: String,
+    },
+    /// The answer is not above an answer that was too low.
+    NotAbove {
+        bound: String,
+    },
+    CoolingDown {
+        wait_secs: u64,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with {answer}.")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already submitted and is wrong."),
+            Refusal::NotBelow { bound } => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high."
+                )
+            }
+            Refusal::NotAbove { bound } => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low."
+                )
+            }
+            Refusal::CoolingDown { wait_secs } => {
+                write!(f, "wait {} before trying again.", format_wait(*wait_secs))
+            }
+        }
+    }
+}
+
+#[must_use]
+pub fn get_submissions_path(year: Year, day: Day) -> PathBuf {
+    get_data_path(year, "submissions", &format!("{day}.jsonl"))
+}
+
+/// Appends an attempt to the log of a day.
+pub fn append(year: Year, day: Day, attempt: &Attempt) -> io::Result<()> {
+    let path = get_submissions_path(year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(attempt).map_err(io::Error::other)?;
+    writeln!(file, "{line}")
+}
+
+/// Reads every attempt of a day, oldest first. A missing log is empty.
+pub fn read(year: Year, day: Day) -> io::Result<Vec<Attempt>> {
+    let file = match fs::File::open(get_submissions_path(year, day)) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    BufReader::new(file)
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |l| !l.trim().is_empty()))
+        .map(|line| serde_json::from_str(&line?).map_err(io::Error::other))
+        .collect()
+}
+
+/// Checks an answer against the earlier attempts of its day before it is submitted at `now`.
+/// Cooldowns apply to the whole day, known answers and bounds only to the same part.
+pub fn check(attempts: &[Attempt], part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+    if let Some(wait_secs) = attempts.last().and_then(|attempt| {
+        let until = attempt.timestamp + attempt.outcome.wait_secs()?;
+        until.checked_sub(now).filter(|secs| *secs > 0)
+    }) {
+        return Err(Refusal::CoolingDown { wait_secs });
+    }
+
+    let attempts: Vec<&Attempt> = attempts.iter().filter(|a| a.part == part).collect();
+
+    if let Some(solved) = attempts
+        .iter()
+        .find(|a| a.outcome == SubmissionOutcome::Correct)
+    {
+        return Err(Refusal::AlreadySolved {
+            answer: solved.answer.clone(),
+        });
+    }
+
+    if attempts
+        .iter()
+        .any(|a| a.outcome.is_wrong() && a.answer == answer)
+    {
+        return Err(Refusal::KnownWrong);
+    }
+
+    let Ok(value) = answer.trim().parse::<i128>() else {
+        return Ok(());
+    };
+
+    let bound = |is_bound: fn(&SubmissionOutcome) -> bool| {
+        attempts
+            .iter()
+            .filter(move |a| is_bound(&a.outcome))
+            .filter_map(|a| a.answer.trim().parse::<i128>().ok())
+    };
+
+    if let Some(high) = bound(|o| matches!(o, SubmissionOutcome::TooHigh { .. })).min() {
+        if value >= high {
+            return Err(Refusal::NotBelow {
+                bound: high.to_string(),
+            });
+        }
+    }
+
+    if let Some(low) = bound(|o| matches!(o, SubmissionOutcome::TooLow { .. })).max() {
+        if value <= low {
+            return Err(Refusal::NotAbove {
+                bound: low.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Seconds since the unix epoch.
+#[must_use]
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Reads the wait time from `You have 1m 30s left to wait` or `please wait 5 minutes before trying again`.
+fn parse_wait(text: &str) -> Option<u64> {
+    if let Some(end) = text.find("left to wait") {
+        let start = text[..end].rfind("You have ")? + "You have ".len();
+        return text[start..end]
+            .split_whitespace()
+            .map(|token| {
+                let unit = token.chars().last()?;
+                let value: u64 = token[..token.len() - unit.len_utf8()].parse().ok()?;
+                match unit {
+                    'h' => Some(value * 3600),
+                    'm' => Some(value * 60),
+                    's' => Some(value),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+
+    let lowercase = text.to_lowercase();
+    let start = lowercase.find("please wait ")? + "please wait ".len();
+    let mut words = lowercase[start..].split_whitespace();
+    let value = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(value * 60),
+        unit if unit.starts_with("second") => Some(value),
+        _ => None,
+    }
+}
+
+fn format_wait(secs: u64) -> String {
+    match (secs / 60, secs % 60) {
+        (0, s) => format!("{s}s"),
+        (m, 0) => format!("{m}m"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, Attempt, Refusal, SubmissionOutcome};
+
+    fn attempt(timestamp: u64, part: u8, answer: &str, outcome: SubmissionOutcome) -> Attempt {
+        Attempt {
+            timestamp,
+            part,
+            answer: answer.into(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            SubmissionOutcome::parse("That's the right answer! You are one gold star closer."),
+            SubmissionOutcome::Correct
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [Return to Day 1]"),
+            SubmissionOutcome::TooHigh { wait_secs: Some(60) }
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer; your answer is too low. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            SubmissionOutcome::TooLow { wait_secs: Some(300) }
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "That's not the right answer. If you're stuck, there are some general tips."
+            ),
+            SubmissionOutcome::Incorrect { wait_secs: None }
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 34s left to wait. [Return to Day 1]"),
+            SubmissionOutcome::RateLimited { wait_secs: Some(94) }
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            SubmissionOutcome::WrongLevel
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(" Something else "),
+            SubmissionOutcome::Unknown {
+                message: "Something else".into()
+            }
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(&"é".repeat(1000)),
+            SubmissionOutcome::Unknown {
+                message: format!("{}…", "é".repeat(300))
+            }
+        );
+    }
+
+    #[test]
+    fn formats_outcomes() {
+        let outcome = SubmissionOutcome::TooHigh {
+            wait_secs: Some(300),
+        };
+        assert_eq!(
+            outcome.to_string(),
+            "That's not the right answer, it is too high. Wait 5m before trying again."
+        );
+        assert_eq!(
+            SubmissionOutcome::RateLimited {
+                wait_secs: Some(94)
+            }
+            .to_string(),
+            "You gave an answer too recently. Wait 1m 34s before trying again."
+        );
+    }
+
+    #[test]
+    fn serializes_attempts() {
+        let attempt = attempt(10, 1, "42", SubmissionOutcome::TooLow { wait_secs: None });
+        let json = serde_json::to_string(&attempt).unwrap();
+        assert_eq!(
+            json,
+            r#"{"timestamp":10,"part":1,"answer":"42","outcome":"too_low"}"#
+        );
+        assert_eq!(serde_json::from_str::<Attempt>(&json).unwrap(), attempt);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let attempts = [
+            attempt(0, 1, "100", SubmissionOutcome::TooHigh { wait_secs: None }),
+            attempt(0, 1, "90", SubmissionOutcome::TooHigh { wait_secs: None }),
+            attempt(0, 1, "10", SubmissionOutcome::TooLow { wait_secs: None }),
+            attempt(
+                0,
+                1,
+                "abc",
+                SubmissionOutcome::Incorrect { wait_secs: None },
+            ),
+            attempt(0, 2, "7", SubmissionOutcome::Correct),
+        ];
+
+        assert_eq!(check(&attempts, 1, "50", 0), Ok(()));
+        assert_eq!(check(&attempts, 1, "xyz", 0), Ok(()));
+        assert_eq!(check(&attempts, 1, "abc", 0), Err(Refusal::KnownWrong));
+        assert_eq!(
+            check(&attempts, 1, "95", 0),
+            Err(Refusal::NotBelow { bound: "90".into() })
+        );
+        assert_eq!(
+            check(&attempts, 1, "5", 0),
+            Err(Refusal::NotAbove { bound: "10".into() })
+        );
+        assert_eq!(
+            check(&attempts, 2, "8", 0),
+            Err(Refusal::AlreadySolved { answer: "7".into() })
+        );
+    }
+
+    #[test]
+    fn honours_cooldowns() {
+        let attempts = [attempt(
+            1000,
+            1,
+            "100",
+            SubmissionOutcome::RateLimited {
+                wait_secs: Some(60),
+            },
+        )];
+
+        assert_eq!(
+            check(&attempts, 2, "5", 1030),
+            Err(Refusal::CoolingDown { wait_secs: 30 })
+        );
+        assert_eq!(check(&attempts, 1, "100", 1060), Ok(()));
+    }
+}